@@ -0,0 +1,72 @@
+use super::*;
+
+/// A generator of binary classification samples, with standard normal features `x` and labels `1` or `-1` by the sign of `weights·x + bias + noise`.
+/// 
+/// Without noise the classes are linearly separable, and noise makes them overlap around the boundary.
+/// Labels can be mapped to `0/1` targets with [`is_positive_label`](crate::metrics::is_positive_label).
+#[derive(Clone, Copy, Debug)]
+pub struct BinaryClassification<const X: usize>
+{
+    pub weights: [f64; X],
+    pub bias: f64,
+    pub(crate) noise: NoiseDistribution,
+    pub seed: u64
+}
+
+impl<const X: usize> BinaryClassification<X>
+{
+    /// # Panics
+    /// 
+    /// If the parameters of `noise` are outside the domain of its distribution, such as a negative scale.
+    pub fn new(weights: [f64; X], bias: f64, noise: Noise, seed: u64) -> Self
+    {
+        Self {
+            weights,
+            bias,
+            noise: noise.distribution(),
+            seed
+        }
+    }
+
+    /// Generates `N` samples, which are the same for the same seed.
+    pub fn generate<const N: usize>(&self) -> ([[f64; X]; N], [f64; N])
+    {
+        let regression = Regression {
+            weights: self.weights,
+            bias: self.bias,
+            noise: self.noise,
+            seed: self.seed
+        };
+        let (x, margin) = regression.generate::<N>();
+
+        (x, margin.map(|margin| if margin >= 0.0 {1.0} else {-1.0}))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let separable = BinaryClassification::new([1.0, -2.0], 0.25, Noise::None, 11);
+        let overlapping = BinaryClassification::new([1.0, -2.0], 0.25, Noise::Gaussian {std_dev: 1.0}, 11);
+
+        let (x, y_true) = separable.generate::<256>();
+        assert_eq!((x, y_true), separable.generate::<256>());
+
+        let errors = |y_true: [f64; 256]| x.into_iter()
+            .zip(y_true)
+            .filter(|&(x, y_true)| (x.mul_dot([1.0, -2.0]) + 0.25)*y_true < 0.0)
+            .count();
+
+        assert_eq!(errors(y_true), 0);
+
+        let (x_overlapping, y_true) = overlapping.generate::<256>();
+        assert_eq!(x_overlapping, x);
+        assert!(errors(y_true) > 0);
+        assert!(y_true.into_iter().any(|y| y > 0.0) && y_true.into_iter().any(|y| y < 0.0));
+    }
+}
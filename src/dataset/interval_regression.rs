@@ -0,0 +1,59 @@
+use rand::Rng;
+
+use super::*;
+
+/// A generator of interval targets for [`TwoParameterLoss`], each an interval of width `width` placed at random around a [`Regression`] target.
+#[derive(Clone, Copy, Debug)]
+pub struct IntervalRegression<const X: usize>
+{
+    pub regression: Regression<X>,
+    pub width: f64
+}
+
+impl<const X: usize> IntervalRegression<X>
+{
+    pub fn new(regression: Regression<X>, width: f64) -> Self
+    {
+        Self {
+            regression,
+            width
+        }
+    }
+
+    /// Generates `N` samples, with the targets laid out as `[y_low, y_high]` pairs as [`TwoParameterLoss`] expects.
+    pub fn generate<const N: usize>(&self) -> ([[f64; X]; N], [f64; 2*N])
+    {
+        let mut rng = seeded_rng(self.regression.seed);
+        let (x, y) = self.regression.generate_from::<_, N>(&mut rng);
+
+        let y_low = y.map(|y| y - self.width*rng.gen::<f64>());
+
+        (x, <[f64; 2*N]>::fill(|i| y_low[i/2] + if i % 2 == 1 {self.width} else {0.0}))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{LossFunction, TwoParameterLoss};
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let regression = Regression::new([1.0, 3.0], -2.0, Noise::None, 3);
+        let interval = IntervalRegression::new(regression, 0.5);
+
+        let (x, y_true) = interval.generate::<64>();
+        assert_eq!((x, y_true), interval.generate::<64>());
+
+        // The noiseless targets lie within their intervals, where the loss is zero
+        let (_, y) = regression.generate::<64>();
+        assert!((0..64).all(|i| {
+            let [y_low, y_high] = [y_true[2*i], y_true[2*i + 1]];
+            y_low <= y[i] && y[i] <= y_high && (y_high - y_low - 0.5).abs() < 1e-12
+        }));
+        assert!(TwoParameterLoss.lf_loss(y_true, y).into_iter().all(|l| l == 0.0));
+    }
+}
@@ -0,0 +1,12 @@
+use super::*;
+
+moddef::moddef!(
+    flat(pub) mod {
+        noise,
+
+        regression,
+        interval_regression,
+        binary_classification,
+        multi_class_classification
+    }
+);
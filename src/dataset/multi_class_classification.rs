@@ -0,0 +1,91 @@
+use std::cmp::Ordering;
+
+use rand::Rng;
+
+use super::*;
+
+/// A generator of multi-class samples, drawn from normal blobs of standard deviation `spread` around the `C` class `centers`.
+/// 
+/// Blobs overlap when `spread` is large relative to the distances between centers.
+/// If `separable`, each sample is instead labeled by its nearest center, making the classes linearly separable.
+#[derive(Clone, Copy, Debug)]
+pub struct MultiClassClassification<const X: usize, const C: usize>
+{
+    pub centers: [[f64; X]; C],
+    pub spread: f64,
+    pub separable: bool,
+    pub seed: u64
+}
+
+impl<const X: usize, const C: usize> MultiClassClassification<X, C>
+{
+    pub fn new(centers: [[f64; X]; C], spread: f64, separable: bool, seed: u64) -> Self
+    {
+        Self {
+            centers,
+            spread,
+            separable,
+            seed
+        }
+    }
+
+    fn nearest_center(&self, x: [f64; X]) -> usize
+    {
+        let distance = |center: &[f64; X]| x.comap(*center, |x, c| (x - c)*(x - c)).sum();
+
+        self.centers.iter()
+            .map(distance)
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(class, _)| class)
+            .unwrap_or(0)
+    }
+
+    /// Generates `N` samples with one-hot labels, whose entries are `1` for the class and `negative` otherwise.
+    /// 
+    /// Use `negative = -1` for the losses in [`multi_class`](crate::multi_class), and `negative = 0` for the cross-entropy losses.
+    pub fn generate<const N: usize>(&self, negative: f64) -> ([[f64; X]; N], [[f64; C]; N])
+    {
+        let mut rng = seeded_rng(self.seed);
+
+        let samples = <[([f64; X], usize); N]>::fill(|_| {
+            let class = rng.gen_range(0..C);
+            let x = self.centers[class].map(|c| c + self.spread*standard_normal(&mut rng));
+            (x, if self.separable {self.nearest_center(x)} else {class})
+        });
+
+        (
+            samples.map(|(x, _)| x),
+            samples.map(|(_, class)| <[f64; C]>::fill(|i| if i == class {1.0} else {negative}))
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::metrics::ConfusionMatrix;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let centers = [[0.0, 0.0], [3.0, 0.0], [0.0, 3.0]];
+        let separable = MultiClassClassification::new(centers, 1.5, true, 5);
+        let overlapping = MultiClassClassification {separable: false, ..separable};
+
+        let (x, y_true) = separable.generate::<256>(-1.0);
+        assert_eq!((x, y_true), separable.generate::<256>(-1.0));
+        assert!(y_true.into_iter().flatten().all(|y| y == 1.0 || y == -1.0));
+
+        // Nearest-center classification is perfect only on the separable samples
+        let y_est = x.map(|x| <[f64; 3]>::fill(|i| if i == separable.nearest_center(x) {1.0} else {0.0}));
+        assert_eq!(ConfusionMatrix::multi_class(&y_true, &y_est).unwrap().accuracy(), 1.0);
+
+        let (x_overlapping, y_true) = overlapping.generate::<256>(0.0);
+        assert_eq!(x_overlapping, x);
+        let accuracy = ConfusionMatrix::multi_class(&y_true, &y_est).unwrap().accuracy();
+        assert!(accuracy < 1.0 && accuracy > 0.5, "Accuracy = {}", accuracy);
+    }
+}
@@ -0,0 +1,121 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::{Distribution, Normal, StandardNormal, StudentT};
+use statrs::distribution::Laplace;
+
+/// A reproducible random number generator from `seed`, shared by all the generators in [`dataset`](crate::dataset).
+/// 
+/// Unlike [`StdRng`](rand::rngs::StdRng), its algorithm is fixed, so the same seed gives the same datasets across versions of `rand`.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng
+{
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// A standard normal sample, used for the features of all generated datasets.
+pub(crate) fn standard_normal<R>(rng: &mut R) -> f64
+where
+    R: Rng + ?Sized
+{
+    StandardNormal.sample(rng)
+}
+
+/// Zero-centered noise added to generated targets.
+/// 
+/// Laplace noise and Student-t noise with few degrees of freedom have heavy tails, producing outliers that robust losses should tolerate.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Noise
+{
+    None,
+    Gaussian {
+        std_dev: f64
+    },
+    Laplace {
+        scale: f64
+    },
+    StudentT {
+        scale: f64,
+        degrees_of_freedom: f64
+    }
+}
+
+impl Noise
+{
+    /// The distribution of the noise, built once so that sampling can't fail.
+    /// 
+    /// # Panics
+    /// 
+    /// If the parameters are outside the domain of the distribution, such as a negative scale.
+    pub(crate) fn distribution(&self) -> NoiseDistribution
+    {
+        match *self
+        {
+            Self::None => NoiseDistribution::None,
+            Self::Gaussian {std_dev} => NoiseDistribution::Gaussian(Normal::new(0.0, std_dev)
+                .expect("Invalid Gaussian noise")
+            ),
+            Self::Laplace {scale} => NoiseDistribution::Laplace(Laplace::new(0.0, scale)
+                .expect("Invalid Laplace noise")
+            ),
+            Self::StudentT {scale, degrees_of_freedom} => NoiseDistribution::StudentT(scale, StudentT::new(degrees_of_freedom)
+                .expect("Invalid Student-t noise")
+            )
+        }
+    }
+}
+
+/// A [Noise] with validated parameters, held by the generators.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum NoiseDistribution
+{
+    None,
+    Gaussian(Normal<f64>),
+    Laplace(Laplace),
+    StudentT(f64, StudentT<f64>)
+}
+
+impl Distribution<f64> for NoiseDistribution
+{
+    fn sample<R>(&self, rng: &mut R) -> f64
+    where
+        R: Rng + ?Sized
+    {
+        match self
+        {
+            Self::None => 0.0,
+            Self::Gaussian(normal) => normal.sample(rng),
+            Self::Laplace(laplace) => laplace.sample(rng),
+            Self::StudentT(scale, student_t) => scale*student_t.sample(rng)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        const N: usize = 100000;
+
+        let mean_and_mad = |noise: Noise| {
+            let mut rng = seeded_rng(0);
+            let noise = noise.distribution();
+            let e: Vec<f64> = (0..N).map(|_| noise.sample(&mut rng)).collect();
+            let mean = e.iter().sum::<f64>()/N as f64;
+            let mad = e.iter().map(|e| e.abs()).sum::<f64>()/N as f64;
+            (mean, mad)
+        };
+
+        assert_eq!(mean_and_mad(Noise::None), (0.0, 0.0));
+
+        // The mean absolute deviation is `std_dev*sqrt(2/π)` for Gaussian noise, and `scale` for Laplace noise
+        let (mean, mad) = mean_and_mad(Noise::Gaussian {std_dev: 2.0});
+        assert!(mean.abs() < 0.05 && (mad - 2.0*(2.0/std::f64::consts::PI).sqrt()).abs() < 0.05, "{} {}", mean, mad);
+        let (mean, mad) = mean_and_mad(Noise::Laplace {scale: 2.0});
+        assert!(mean.abs() < 0.05 && (mad - 2.0).abs() < 0.05, "{} {}", mean, mad);
+        let (mean, _) = mean_and_mad(Noise::StudentT {scale: 1.0, degrees_of_freedom: 3.0});
+        assert!(mean.abs() < 0.05, "{}", mean);
+    }
+}
@@ -0,0 +1,78 @@
+use rand::Rng;
+use rand_distr::Distribution;
+
+use super::*;
+
+/// A generator of regression samples, with standard normal features `x` and targets `y_true = weights·x + bias + noise`.
+#[derive(Clone, Copy, Debug)]
+pub struct Regression<const X: usize>
+{
+    pub weights: [f64; X],
+    pub bias: f64,
+    pub(crate) noise: NoiseDistribution,
+    pub seed: u64
+}
+
+impl<const X: usize> Regression<X>
+{
+    /// # Panics
+    /// 
+    /// If the parameters of `noise` are outside the domain of its distribution, such as a negative scale.
+    pub fn new(weights: [f64; X], bias: f64, noise: Noise, seed: u64) -> Self
+    {
+        Self {
+            weights,
+            bias,
+            noise: noise.distribution(),
+            seed
+        }
+    }
+
+    /// Generates `N` samples, which are the same for the same seed.
+    pub fn generate<const N: usize>(&self) -> ([[f64; X]; N], [f64; N])
+    {
+        self.generate_from(&mut seeded_rng(self.seed))
+    }
+
+    /// Generates `N` samples from `rng`, leaving it ready to draw anything that goes with them.
+    pub(crate) fn generate_from<R, const N: usize>(&self, rng: &mut R) -> ([[f64; X]; N], [f64; N])
+    where
+        R: Rng
+    {
+        let x = <[[f64; X]; N]>::fill(|_| <[f64; X]>::fill(|_| standard_normal(rng)));
+        let y_true = x.map(|x| x.mul_dot(self.weights) + self.bias + self.noise.sample(rng));
+
+        (x, y_true)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{LossFunction, MeanSquareError};
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let regression = Regression::new([2.0, -1.0], 0.5, Noise::Gaussian {std_dev: 0.1}, 7);
+
+        let (x, y_true): ([[f64; 2]; 256], [f64; 256]) = regression.generate();
+        assert_eq!((x, y_true), regression.generate());
+        assert_ne!(x, Regression {seed: 8, ..regression}.generate::<256>().0);
+
+        let y_exact = x.map(|x| x.mul_dot([2.0, -1.0]) + 0.5);
+        let [mse] = MeanSquareError.lf_loss(y_true, y_exact);
+        assert!((mse.sqrt() - 0.1).abs() < 0.02, "Noise RMS = {}", mse.sqrt());
+
+        let (_, y_true_exact) = Regression::new([2.0, -1.0], 0.5, Noise::None, 7).generate::<256>();
+        assert_eq!(y_true_exact, y_exact);
+    }
+    #[test]
+    #[should_panic]
+    fn test_invalid_noise()
+    {
+        Regression::new([2.0, -1.0], 0.5, Noise::Laplace {scale: -1.0}, 7);
+    }
+}
@@ -0,0 +1,593 @@
+use std::{num::FpCategory, ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign}, cmp::Ordering};
+
+use num::{Float, Num, NumCast, One, ToPrimitive, Zero};
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// A dual number `re + du*ε`, where `ε² = 0`, for forward-mode automatic differentiation.
+///
+/// Evaluating a function on a dual number with `du = 1` yields the function's value in `re` and its derivative in `du`.
+/// Comparisons only consider the real part, so branches take the same path as they would for `re` alone.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<F>
+{
+    pub re: F,
+    pub du: F
+}
+
+impl<F> Dual<F>
+{
+    pub const fn new(re: F, du: F) -> Self
+    {
+        Self {
+            re,
+            du
+        }
+    }
+}
+
+impl<F> Dual<F>
+where
+    F: Float
+{
+    /// A value which is held constant, with a derivative of zero.
+    pub fn constant(re: F) -> Self
+    {
+        Self::new(re, F::zero())
+    }
+
+    /// The variable being differentiated with respect to, with a derivative of one.
+    pub fn variable(re: F) -> Self
+    {
+        Self::new(re, F::one())
+    }
+
+    fn chain(self, re: F, d: F) -> Self
+    {
+        Self::new(re, self.du*d)
+    }
+}
+
+impl<F> PartialEq for Dual<F>
+where
+    F: PartialEq
+{
+    fn eq(&self, other: &Self) -> bool
+    {
+        self.re == other.re
+    }
+}
+
+impl<F> PartialOrd for Dual<F>
+where
+    F: PartialOrd
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        self.re.partial_cmp(&other.re)
+    }
+}
+
+impl<F> Neg for Dual<F>
+where
+    F: Neg<Output = F>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        Self::new(-self.re, -self.du)
+    }
+}
+
+impl<F> Add for Dual<F>
+where
+    F: Float
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Self::new(self.re + rhs.re, self.du + rhs.du)
+    }
+}
+
+impl<F> Sub for Dual<F>
+where
+    F: Float
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        Self::new(self.re - rhs.re, self.du - rhs.du)
+    }
+}
+
+impl<F> Mul for Dual<F>
+where
+    F: Float
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        Self::new(self.re*rhs.re, self.du*rhs.re + self.re*rhs.du)
+    }
+}
+
+impl<F> Div for Dual<F>
+where
+    F: Float
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self
+    {
+        Self::new(self.re/rhs.re, (self.du*rhs.re - self.re*rhs.du)/(rhs.re*rhs.re))
+    }
+}
+
+impl<F> Rem for Dual<F>
+where
+    F: Float
+{
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self
+    {
+        Self::new(self.re % rhs.re, self.du - rhs.du*(self.re/rhs.re).trunc())
+    }
+}
+
+impl<F> AddAssign for Dual<F>
+where
+    F: Float
+{
+    fn add_assign(&mut self, rhs: Self)
+    {
+        *self = *self + rhs
+    }
+}
+
+impl<F> SubAssign for Dual<F>
+where
+    F: Float
+{
+    fn sub_assign(&mut self, rhs: Self)
+    {
+        *self = *self - rhs
+    }
+}
+
+impl<F> MulAssign for Dual<F>
+where
+    F: Float
+{
+    fn mul_assign(&mut self, rhs: Self)
+    {
+        *self = *self*rhs
+    }
+}
+
+impl<F> DivAssign for Dual<F>
+where
+    F: Float
+{
+    fn div_assign(&mut self, rhs: Self)
+    {
+        *self = *self/rhs
+    }
+}
+
+impl<F> RemAssign for Dual<F>
+where
+    F: Float
+{
+    fn rem_assign(&mut self, rhs: Self)
+    {
+        *self = *self % rhs
+    }
+}
+
+impl<F> Zero for Dual<F>
+where
+    F: Float
+{
+    fn zero() -> Self
+    {
+        Self::constant(F::zero())
+    }
+    fn is_zero(&self) -> bool
+    {
+        self.re.is_zero() && self.du.is_zero()
+    }
+}
+
+impl<F> ZeroConst for Dual<F>
+where
+    F: Float + ZeroConst
+{
+    const ZERO: Self = Self::new(F::ZERO, F::ZERO);
+
+    fn is_zero2(self) -> bool
+    {
+        self.re.is_zero2() && self.du.is_zero2()
+    }
+}
+
+impl<F> One for Dual<F>
+where
+    F: Float
+{
+    fn one() -> Self
+    {
+        Self::constant(F::one())
+    }
+}
+
+impl<F> Num for Dual<F>
+where
+    F: Float
+{
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr>
+    {
+        F::from_str_radix(str, radix).map(Self::constant)
+    }
+}
+
+impl<F> ToPrimitive for Dual<F>
+where
+    F: ToPrimitive
+{
+    fn to_i64(&self) -> Option<i64>
+    {
+        self.re.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64>
+    {
+        self.re.to_u64()
+    }
+    fn to_f32(&self) -> Option<f32>
+    {
+        self.re.to_f32()
+    }
+    fn to_f64(&self) -> Option<f64>
+    {
+        self.re.to_f64()
+    }
+}
+
+impl<F> NumCast for Dual<F>
+where
+    F: Float
+{
+    fn from<T: ToPrimitive>(n: T) -> Option<Self>
+    {
+        F::from(n).map(Self::constant)
+    }
+}
+
+impl<F> Float for Dual<F>
+where
+    F: Float
+{
+    fn nan() -> Self
+    {
+        Self::constant(F::nan())
+    }
+    fn infinity() -> Self
+    {
+        Self::constant(F::infinity())
+    }
+    fn neg_infinity() -> Self
+    {
+        Self::constant(F::neg_infinity())
+    }
+    fn neg_zero() -> Self
+    {
+        Self::constant(F::neg_zero())
+    }
+    fn min_value() -> Self
+    {
+        Self::constant(F::min_value())
+    }
+    fn min_positive_value() -> Self
+    {
+        Self::constant(F::min_positive_value())
+    }
+    fn epsilon() -> Self
+    {
+        Self::constant(F::epsilon())
+    }
+    fn max_value() -> Self
+    {
+        Self::constant(F::max_value())
+    }
+
+    fn is_nan(self) -> bool
+    {
+        self.re.is_nan()
+    }
+    fn is_infinite(self) -> bool
+    {
+        self.re.is_infinite()
+    }
+    fn is_finite(self) -> bool
+    {
+        self.re.is_finite()
+    }
+    fn is_normal(self) -> bool
+    {
+        self.re.is_normal()
+    }
+    fn classify(self) -> FpCategory
+    {
+        self.re.classify()
+    }
+    fn is_sign_positive(self) -> bool
+    {
+        self.re.is_sign_positive()
+    }
+    fn is_sign_negative(self) -> bool
+    {
+        self.re.is_sign_negative()
+    }
+    fn integer_decode(self) -> (u64, i16, i8)
+    {
+        self.re.integer_decode()
+    }
+
+    fn floor(self) -> Self
+    {
+        Self::constant(self.re.floor())
+    }
+    fn ceil(self) -> Self
+    {
+        Self::constant(self.re.ceil())
+    }
+    fn round(self) -> Self
+    {
+        Self::constant(self.re.round())
+    }
+    fn trunc(self) -> Self
+    {
+        Self::constant(self.re.trunc())
+    }
+    fn fract(self) -> Self
+    {
+        Self::new(self.re.fract(), self.du)
+    }
+    fn abs(self) -> Self
+    {
+        if self.re.is_sign_negative()
+        {
+            -self
+        }
+        else
+        {
+            self
+        }
+    }
+    fn signum(self) -> Self
+    {
+        Self::constant(self.re.signum())
+    }
+    fn max(self, other: Self) -> Self
+    {
+        if other.re > self.re || self.re.is_nan()
+        {
+            other
+        }
+        else
+        {
+            self
+        }
+    }
+    fn min(self, other: Self) -> Self
+    {
+        if other.re < self.re || self.re.is_nan()
+        {
+            other
+        }
+        else
+        {
+            self
+        }
+    }
+    fn abs_sub(self, other: Self) -> Self
+    {
+        if self.re > other.re
+        {
+            self - other
+        }
+        else
+        {
+            Self::zero()
+        }
+    }
+
+    fn mul_add(self, a: Self, b: Self) -> Self
+    {
+        self*a + b
+    }
+    fn recip(self) -> Self
+    {
+        let recip = self.re.recip();
+        self.chain(recip, -recip*recip)
+    }
+    fn powi(self, n: i32) -> Self
+    {
+        if n == 0
+        {
+            return Self::one()
+        }
+        self.chain(self.re.powi(n), f!(n; F)*self.re.powi(n - 1))
+    }
+    fn powf(self, n: Self) -> Self
+    {
+        let pow = self.re.powf(n.re);
+        let mut du = F::zero();
+        if !self.du.is_zero()
+        {
+            du = du + self.du*n.re*self.re.powf(n.re - F::one());
+        }
+        if !n.du.is_zero()
+        {
+            du = du + n.du*pow*self.re.ln();
+        }
+        Self::new(pow, du)
+    }
+    fn sqrt(self) -> Self
+    {
+        let sqrt = self.re.sqrt();
+        self.chain(sqrt, (sqrt + sqrt).recip())
+    }
+    fn cbrt(self) -> Self
+    {
+        let cbrt = self.re.cbrt();
+        self.chain(cbrt, (f!(3.0; F)*cbrt*cbrt).recip())
+    }
+    fn hypot(self, other: Self) -> Self
+    {
+        let hypot = self.re.hypot(other.re);
+        Self::new(hypot, (self.re*self.du + other.re*other.du)/hypot)
+    }
+
+    fn exp(self) -> Self
+    {
+        let exp = self.re.exp();
+        self.chain(exp, exp)
+    }
+    fn exp2(self) -> Self
+    {
+        let exp2 = self.re.exp2();
+        self.chain(exp2, exp2*f!(2.0; F).ln())
+    }
+    fn exp_m1(self) -> Self
+    {
+        self.chain(self.re.exp_m1(), self.re.exp())
+    }
+    fn ln(self) -> Self
+    {
+        self.chain(self.re.ln(), self.re.recip())
+    }
+    fn log(self, base: Self) -> Self
+    {
+        self.ln()/base.ln()
+    }
+    fn log2(self) -> Self
+    {
+        self.chain(self.re.log2(), (self.re*f!(2.0; F).ln()).recip())
+    }
+    fn log10(self) -> Self
+    {
+        self.chain(self.re.log10(), (self.re*f!(10.0; F).ln()).recip())
+    }
+    fn ln_1p(self) -> Self
+    {
+        self.chain(self.re.ln_1p(), (F::one() + self.re).recip())
+    }
+
+    fn sin(self) -> Self
+    {
+        self.chain(self.re.sin(), self.re.cos())
+    }
+    fn cos(self) -> Self
+    {
+        self.chain(self.re.cos(), -self.re.sin())
+    }
+    fn tan(self) -> Self
+    {
+        let cos = self.re.cos();
+        self.chain(self.re.tan(), (cos*cos).recip())
+    }
+    fn sin_cos(self) -> (Self, Self)
+    {
+        let (sin, cos) = self.re.sin_cos();
+        (self.chain(sin, cos), self.chain(cos, -sin))
+    }
+    fn asin(self) -> Self
+    {
+        self.chain(self.re.asin(), (F::one() - self.re*self.re).sqrt().recip())
+    }
+    fn acos(self) -> Self
+    {
+        self.chain(self.re.acos(), -(F::one() - self.re*self.re).sqrt().recip())
+    }
+    fn atan(self) -> Self
+    {
+        self.chain(self.re.atan(), (F::one() + self.re*self.re).recip())
+    }
+    fn atan2(self, other: Self) -> Self
+    {
+        Self::new(
+            self.re.atan2(other.re),
+            (other.re*self.du - self.re*other.du)/(self.re*self.re + other.re*other.re)
+        )
+    }
+
+    fn sinh(self) -> Self
+    {
+        self.chain(self.re.sinh(), self.re.cosh())
+    }
+    fn cosh(self) -> Self
+    {
+        self.chain(self.re.cosh(), self.re.sinh())
+    }
+    fn tanh(self) -> Self
+    {
+        let tanh = self.re.tanh();
+        self.chain(tanh, F::one() - tanh*tanh)
+    }
+    fn asinh(self) -> Self
+    {
+        self.chain(self.re.asinh(), (self.re*self.re + F::one()).sqrt().recip())
+    }
+    fn acosh(self) -> Self
+    {
+        self.chain(self.re.acosh(), (self.re*self.re - F::one()).sqrt().recip())
+    }
+    fn atanh(self) -> Self
+    {
+        self.chain(self.re.atanh(), (F::one() - self.re*self.re).recip())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Float;
+
+    use super::Dual;
+
+    #[test]
+    fn test()
+    {
+        const TOLERANCE: f64 = 1e-12;
+
+        let x = 0.7;
+        let d = |f: fn(Dual<f64>) -> Dual<f64>| f(Dual::variable(x)).du;
+
+        let cases: [(fn(Dual<f64>) -> Dual<f64>, f64); 10] = [
+            (|x| x*x*x, 3.0*x*x),
+            (|x| x.recip(), -1.0/(x*x)),
+            (|x| x.powf(Dual::constant(2.5)), 2.5*x.powf(1.5)),
+            (|x| Dual::constant(2.0).powf(x), 2f64.powf(x)*2f64.ln()),
+            (|x| x.sqrt(), 0.5/x.sqrt()),
+            (|x| x.exp().ln_1p(), x.exp()/(1.0 + x.exp())),
+            (|x| x.atan()*Dual::constant(2.0) - Dual::constant(1.0), 2.0/(1.0 + x*x)),
+            (|x| x.tanh(), 1.0 - x.tanh()*x.tanh()),
+            (|x| (-x).abs().max(Dual::constant(0.5)), 1.0),
+            (|x| x.sin()/x.cos(), 1.0/(x.cos()*x.cos()))
+        ];
+
+        for (i, (f, d_expected)) in cases.into_iter().enumerate()
+        {
+            assert!((d(f) - d_expected).abs() < TOLERANCE, "Derivative mismatch in case {}: {} != {}", i, d(f), d_expected);
+        }
+    }
+}
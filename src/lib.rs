@@ -6,7 +6,15 @@
 
 moddef::moddef!(
     flat(pub) mod {
-        loss_function
+        loss_function,
+        dual
+    },
+    pub mod {
+        metrics,
+        optim,
+        model,
+        regularization,
+        dataset
     },
     mod {
         plot for cfg(test)
@@ -18,10 +26,10 @@ use array_math::*;
 #[cfg(test)]
 mod tests
 {
-    use std::{fmt::Debug, ops::{Div, AddAssign}};
+    use std::{fmt::Debug, ops::{Div, AddAssign, Range}};
 
     use linspace::LinspaceArray;
-    use num::NumCast;
+    use num::{NumCast, ToPrimitive};
     use num_identities_const::ZeroConst;
     use plotters::style::HSLColor;
 
@@ -43,8 +51,238 @@ mod tests
         let l_grad = lf.lf_loss_grad(y_true, y_est);
 
         println!("Gradient of loss = {:?}", l_grad);
+
+        let (l_fused, l_grad_fused) = lf.lf_loss_and_grad(y_true, y_est);
+
+        let approx_eq = |(a, b): (F, F)| {
+            let (a, b) = (a.to_f64().unwrap(), b.to_f64().unwrap());
+            a == b || (a - b).abs() <= 1e-12*(1.0 + a.abs().max(b.abs()))
+        };
+        assert!(l.into_iter().zip(l_fused).all(approx_eq), "Fused loss mismatch: {:?} != {:?}", l_fused, l);
+        assert!(l_grad.into_iter().flatten().zip(l_grad_fused.into_iter().flatten()).all(approx_eq), "Fused loss gradient mismatch: {:?} != {:?}", l_grad_fused, l_grad);
     }
-    
+
+    pub fn test_dyn<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: T, y_true: [F; Y_TRUE], y_est: [F; Y_EST])
+    where
+        F: Copy + Debug + PartialEq + ZeroConst,
+        T: LossFunction<F, Y_TRUE, Y_EST, L> + DynLossFunction<F>
+    {
+        let mut l = vec![F::ZERO; L];
+        lf.lf_dyn_loss(&y_true, &y_est, &mut l).expect("Dyn loss error");
+        assert_eq!(l, lf.lf_loss(y_true, y_est));
+
+        let mut l_grad = vec![F::ZERO; L*Y_EST];
+        lf.lf_dyn_loss_grad(&y_true, &y_est, &mut l_grad).expect("Dyn loss gradient error");
+        assert!(l_grad.iter().eq(lf.lf_loss_grad(y_true, y_est).iter().flatten()));
+
+        assert_eq!(
+            lf.lf_dyn_loss(&y_true, &y_est, &mut vec![F::ZERO; L + 1]),
+            Err(LossFunctionError::LossLength {expected: L, found: L + 1})
+        );
+        assert_eq!(
+            lf.lf_dyn_loss_grad(&y_true[1..], &y_est, &mut l_grad),
+            Err(LossFunctionError::YTrueLength {expected: Y_TRUE, found: Y_TRUE - 1})
+        );
+    }
+
+    pub fn test_gradient<T, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: T, y_true: [f64; Y_TRUE], y_est: [f64; Y_EST])
+    where
+        T: LossFunction<f64, Y_TRUE, Y_EST, L>
+    {
+        const H: f64 = 1e-6;
+        const TOLERANCE: f64 = 1e-5;
+
+        let error = gradient_check(&lf, y_true, y_est, H);
+
+        println!("Gradient check error = {:?}", error);
+
+        for (i, error) in error.into_iter().enumerate()
+        {
+            for (k, error) in error.into_iter().enumerate()
+            {
+                assert!(error <= TOLERANCE, "Gradient mismatch at {:?}: relative error {}", (i, k), error);
+            }
+        }
+    }
+
+    pub fn test_weighted<T, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: T, y_true: [f64; Y_TRUE], y_est: [f64; Y_EST], weights: [f64; Y_EST])
+    where
+        T: WeightedLossFunction<f64, Y_TRUE, Y_EST, L>
+    {
+        let approx_eq = |(a, b): (f64, f64)| a == b || (a - b).abs() <= 1e-12*(1.0 + a.abs().max(b.abs()));
+
+        let (l, l_grad) = lf.lf_loss_and_grad(y_true, y_est);
+        let (l_ones, l_grad_ones) = lf.lf_weighted_loss_and_grad(y_true, y_est, [1.0; Y_EST]);
+        assert!(l.into_iter().zip(l_ones).all(approx_eq), "Unit weighted loss mismatch: {:?} != {:?}", l_ones, l);
+        assert!(l_grad.into_iter().flatten().zip(l_grad_ones.into_iter().flatten()).all(approx_eq), "Unit weighted loss gradient mismatch: {:?} != {:?}", l_grad_ones, l_grad);
+
+        let l = lf.lf_weighted_loss(y_true, y_est, weights);
+        let l_grad = lf.lf_weighted_loss_grad(y_true, y_est, weights);
+
+        println!("Weighted loss = {:?}", l);
+        println!("Gradient of weighted loss = {:?}", l_grad);
+
+        let (l_fused, l_grad_fused) = lf.lf_weighted_loss_and_grad(y_true, y_est, weights);
+        assert!(l.into_iter().zip(l_fused).all(approx_eq), "Fused weighted loss mismatch: {:?} != {:?}", l_fused, l);
+        assert!(l_grad.into_iter().flatten().zip(l_grad_fused.into_iter().flatten()).all(approx_eq), "Fused weighted loss gradient mismatch: {:?} != {:?}", l_grad_fused, l_grad);
+
+        test_gradient(ClassWeighted::new(lf, weights), y_true, y_est);
+    }
+
+    pub fn test_hessian<T, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: T, y_true: [f64; Y_TRUE], y_est: [f64; Y_EST])
+    where
+        T: LossFunctionHessian<f64, Y_TRUE, Y_EST, L>
+    {
+        const H: f64 = 1e-6;
+        const TOLERANCE: f64 = 1e-4;
+
+        let l_hess = lf.lf_loss_hessian(y_true, y_est);
+
+        println!("Hessian of loss = {:?}", l_hess);
+
+        let l_hess_diag = lf.lf_loss_hessian_diagonal(y_true, y_est);
+
+        for i in 0..L
+        {
+            for k in 0..Y_EST
+            {
+                assert!((l_hess_diag[i][k] - l_hess[i][k][k]).abs() <= TOLERANCE, "Hessian diagonal mismatch at {:?}", (i, k));
+
+                let mut y_est_pos = y_est;
+                let mut y_est_neg = y_est;
+                y_est_pos[k] += H;
+                y_est_neg[k] -= H;
+                let l_grad_pos = lf.lf_loss_grad(y_true, y_est_pos);
+                let l_grad_neg = lf.lf_loss_grad(y_true, y_est_neg);
+
+                for j in 0..Y_EST
+                {
+                    let d = (l_grad_pos[i][j] - l_grad_neg[i][j])/(2.0*H);
+                    assert!((d - l_hess[i][j][k]).abs() <= TOLERANCE*(1.0 + d.abs()), "Hessian mismatch at {:?}: {} != {}", (i, j, k), l_hess[i][j][k], d);
+                }
+            }
+        }
+    }
+
+    pub fn test_properties<T, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: T, y_true: [f64; Y_TRUE], y_est: [f64; Y_EST], y_est_range: Range<f64>)
+    where
+        T: LossFunction<f64, Y_TRUE, Y_EST, L> + LossProperties
+    {
+        const STEPS: usize = 1024;
+        const TOLERANCE: f64 = 1e-9;
+
+        let h = (y_est_range.end - y_est_range.start)/STEPS as f64;
+        let mut non_convex = false;
+
+        for k in 0..Y_EST
+        {
+            let samples: Vec<_> = (0..=STEPS).map(|n| {
+                    let mut y_est = y_est;
+                    y_est[k] = y_est_range.start + h*n as f64;
+                    lf.lf_loss_and_grad(y_true, y_est)
+                }).collect();
+
+            for i in 0..L
+            {
+                for (n, (l, l_grad)) in samples.iter().enumerate()
+                {
+                    if let Some(bound) = lf.lf_bound()
+                    {
+                        assert!(l[i] <= bound + TOLERANCE, "Loss above its bound at {:?}: {} > {}", (i, k, n), l[i], bound);
+                    }
+                    if n > 0
+                    {
+                        let (l_prev, l_grad_prev) = &samples[n - 1];
+
+                        if let Some(lipschitz) = lf.lf_lipschitz()
+                        {
+                            let d = (l[i] - l_prev[i]).abs();
+                            assert!(d <= lipschitz*h + TOLERANCE, "Loss not {}-Lipschitz at {:?}: {} > {}", lipschitz, (i, k, n), d, lipschitz*h);
+                        }
+                        if let Some(smoothness) = lf.lf_smoothness()
+                        {
+                            let d = (l_grad[i][k] - l_grad_prev[i][k]).abs();
+                            assert!(d <= smoothness*h + TOLERANCE, "Loss not {}-smooth at {:?}: {} > {}", smoothness, (i, k, n), d, smoothness*h);
+                        }
+                    }
+                    if n > 0 && n < STEPS
+                    {
+                        let d2 = samples[n - 1].0[i] - 2.0*l[i] + samples[n + 1].0[i];
+                        if lf.lf_is_convex()
+                        {
+                            assert!(d2 >= -TOLERANCE, "Loss not convex at {:?}: second difference {}", (i, k, n), d2);
+                        }
+                        else if d2 < -TOLERANCE
+                        {
+                            non_convex = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        assert!(lf.lf_is_convex() || non_convex, "Loss claimed non-convex, but no non-convexity was found in {:?}", y_est_range);
+    }
+
+    pub fn test_optimizer<O>(mut opt: O, steps: usize)
+    where
+        O: optim::Optimizer<f64, 2>
+    {
+        const TOLERANCE: f64 = 1e-2;
+
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_true = x.map(|x| 2.0*x + 1.0);
+        let jacobian = x.map(|x| [x, 1.0]);
+
+        let mut params = [0.0, 0.0];
+        for _ in 0..steps
+        {
+            let [w, b] = params;
+            let y_est = x.map(|x| w*x + b);
+            opt.opt_step_loss_grad(&mut params, MeanSquareError.lf_loss_grad(y_true, y_est), jacobian);
+        }
+
+        println!("Parameters = {:?}", params);
+
+        assert!((params[0] - 2.0).abs() < TOLERANCE && (params[1] - 1.0).abs() < TOLERANCE, "Linear fit did not converge: {:?}", params);
+    }
+
+    pub fn test_regularizer<R>(reg: R, w_expected: [f64; 3])
+    where
+        R: regularization::Regularizer
+    {
+        const STEPS: usize = 256;
+        const STEP: f64 = 0.25;
+        const H: f64 = 1e-6;
+        const TOLERANCE: f64 = 1e-9;
+
+        // With an orthogonal design, the regularized least squares solution has a closed form to compare against
+        let x = [[1.0, 1.0, 1.0], [-1.0, 1.0, -1.0], [1.0, -1.0, -1.0], [-1.0, -1.0, 1.0]];
+        let y_true = x.map(|x| x.mul_dot([3.0, -0.2, 1.0]));
+
+        let mut w = [0.0; 3];
+        for _ in 0..STEPS
+        {
+            let y_est = x.map(|x| x.mul_dot(w));
+            let grad = optim::chain_loss_grad(MeanSquareError.lf_loss_grad(y_true, y_est), x);
+            w = reg.reg_prox(w.comap(grad, |w, grad| w - STEP*grad), STEP);
+        }
+
+        println!("Parameters = {:?}", w);
+
+        assert!(w.comap(w_expected, |w, w_expected| (w - w_expected).abs()).into_iter().all(|e| e < TOLERANCE), "Proximal gradient descent did not converge: {:?} != {:?}", w, w_expected);
+
+        let w = [0.7, -1.3, 2.1];
+        let grad = reg.reg_penalty_grad(w);
+        for k in 0..3
+        {
+            let mut w_h = w;
+            w_h[k] += H;
+            let grad_approx = (reg.reg_penalty(w_h) - reg.reg_penalty(w))/H;
+            assert!((grad[k] - grad_approx).abs() < 1e-4, "Penalty gradient mismatch at {}: {} != {}", k, grad[k], grad_approx);
+        }
+    }
+
     const N: usize = 64;
     const HUE_SATURATION: f64 = 1.618;
     const SHADOW_SATURATION: f64 = 0.5;
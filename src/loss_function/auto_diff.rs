@@ -0,0 +1,127 @@
+use num::Float;
+
+use super::*;
+
+/// A loss function which only defines its loss, leaving the gradient to [AutoDiff](AutoDiff).
+pub trait LossFunctionValue<F, const Y_TRUE: usize, const Y_EST: usize, const L: usize = 1>
+{
+    fn lf_loss_value(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [F; L];
+}
+
+/// Turns a [LossFunctionValue](LossFunctionValue) into a [LossFunction](LossFunction), computing its gradient by forward-mode automatic differentiation.
+///
+/// The loss is evaluated once on [dual numbers](Dual) for each component of `y_est`.
+#[derive(Clone, Copy, Debug)]
+pub struct AutoDiff<T>(pub T);
+
+impl<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunction<F, Y_TRUE, Y_EST, L> for AutoDiff<T>
+where
+    T: LossFunctionValue<Dual<F>, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [F; L]
+    {
+        self.0.lf_loss_value(y_true.map(Dual::constant), y_est.map(Dual::constant))
+            .map(|l| l.re)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        forward_mode(|y_true, y_est| self.0.lf_loss_value(y_true, y_est), y_true, y_est)
+    }
+}
+
+/// Computes the gradient of any loss function which is generic over its float type by forward-mode automatic differentiation.
+///
+/// Useful for checking a hand-written [lf_loss_grad](LossFunction::lf_loss_grad) against the derivative of its [lf_loss](LossFunction::lf_loss).
+pub fn autodiff_grad<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: &T, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+where
+    T: LossFunction<Dual<F>, Y_TRUE, Y_EST, L> + ?Sized,
+    F: Float
+{
+    forward_mode(|y_true, y_est| lf.lf_loss(y_true, y_est), y_true, y_est)
+}
+
+fn forward_mode<F, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(
+    loss: impl Fn([Dual<F>; Y_TRUE], [Dual<F>; Y_EST]) -> [Dual<F>; L],
+    y_true: [F; Y_TRUE],
+    y_est: [F; Y_EST]
+) -> [[F; Y_EST]; L]
+where
+    F: Float
+{
+    let y_true = y_true.map(Dual::constant);
+
+    let l_grad_t = <[[F; L]; Y_EST]>::fill(|k| {
+        let y_est = <[Dual<F>; Y_EST]>::fill(|j| if j == k
+        {
+            Dual::variable(y_est[j])
+        }
+        else
+        {
+            Dual::constant(y_est[j])
+        });
+        loss(y_true, y_est).map(|l| l.du)
+    });
+
+    <[[F; Y_EST]; L]>::fill(|i| <[F; Y_EST]>::fill(|k| l_grad_t[k][i]))
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Float;
+
+    use crate::{tests as t, multi_class::{LogisticLoss, SavageLoss, TangentLoss}, CategoricalCrossEntropyLoss, HuberLoss, MeanSquareError};
+    use super::*;
+
+    /// Log-cosh loss, `ln(cosh(y_est - y_true))`, with no hand-written gradient.
+    #[derive(Clone, Copy, Debug)]
+    struct LogCoshLoss;
+
+    impl<F, const Y: usize> LossFunctionValue<F, Y, Y, Y> for LogCoshLoss
+    where
+        F: Float
+    {
+        fn lf_loss_value(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+        {
+            y_true.comap(y_est, |y_true, y_est| (y_est - y_true).cosh().ln())
+        }
+    }
+
+    fn assert_grad_eq<const Y_EST: usize, const L: usize>(l_grad: [[f64; Y_EST]; L], l_grad_ad: [[f64; Y_EST]; L])
+    {
+        assert!(
+            l_grad.into_iter().flatten().zip(l_grad_ad.into_iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-12),
+            "Autodiff gradient mismatch: {:?} != {:?}", l_grad_ad, l_grad
+        );
+    }
+
+    #[test]
+    fn test()
+    {
+        t::test(AutoDiff(LogCoshLoss), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(AutoDiff(LogCoshLoss), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+
+        let l_grad = AutoDiff(LogCoshLoss).lf_loss_grad([1.0, 2.0], [1.5, 0.0]);
+        assert_grad_eq(l_grad, [[0.5f64.tanh(), 0.0], [0.0, (-2.0f64).tanh()]]);
+    }
+
+    #[test]
+    fn test_autodiff_grad()
+    {
+        let y_true = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_est = [1.2, 2.0, 4.0, 5.0, 4.0];
+        assert_grad_eq(MeanSquareError.lf_loss_grad(y_true, y_est), autodiff_grad(&MeanSquareError, y_true, y_est));
+        assert_grad_eq(HuberLoss::new(0.5).lf_loss_grad(y_true, y_est), autodiff_grad(&HuberLoss::new(0.5), y_true, y_est));
+
+        let y_true = [0.1, 0.6, 0.1, 0.1, 0.1];
+        let y_est = [1.0, 3.0, -2.0, 0.5, 0.0];
+        assert_grad_eq(CategoricalCrossEntropyLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&CategoricalCrossEntropyLoss, y_true, y_est));
+
+        let y_true = [1.0, -1.0, 1.0, 1.0, -1.0];
+        let y_est = [0.6, -1.2, 0.8, 1.5, -1.3];
+        assert_grad_eq(LogisticLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&LogisticLoss, y_true, y_est));
+        assert_grad_eq(SavageLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&SavageLoss, y_true, y_est));
+        assert_grad_eq(TangentLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&TangentLoss, y_true, y_est));
+    }
+}
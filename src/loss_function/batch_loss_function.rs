@@ -0,0 +1,240 @@
+use num::Float;
+
+use super::*;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatchLoss<F, const Y_EST: usize, const L: usize>
+{
+    /// The loss of each sample in the batch.
+    pub losses: Vec<[F; L]>,
+    /// The reduced loss, or `None` if the reduction is [Reduction::None](Reduction::None).
+    pub loss: Option<[F; L]>,
+    /// The gradient of the reduced loss, or `None` if the reduction is [Reduction::None](Reduction::None).
+    pub loss_grad: Option<[[F; Y_EST]; L]>
+}
+
+/// Evaluates a loss function over many samples at once.
+/// 
+/// Samples are given either as slices of arrays, or as flat row-major buffers where each sample follows the previous one.
+pub trait BatchLossFunction<F, const Y_TRUE: usize, const Y_EST: usize, const L: usize = 1>: LossFunction<F, Y_TRUE, Y_EST, L>
+{
+    fn lf_batch_loss(&self, y_true: &[[F; Y_TRUE]], y_est: &[[F; Y_EST]], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>;
+    fn lf_batch_loss_grad(&self, y_true: &[[F; Y_TRUE]], y_est: &[[F; Y_EST]]) -> Result<Vec<[[F; Y_EST]; L]>, LossFunctionError>;
+
+    fn lf_batch_loss_flat(&self, y_true: &[F], y_est: &[F], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>;
+    fn lf_batch_loss_grad_flat(&self, y_true: &[F], y_est: &[F]) -> Result<Vec<[[F; Y_EST]; L]>, LossFunctionError>;
+
+    /// Like [lf_batch_loss](BatchLossFunction::lf_batch_loss), but with the loss and gradient of each sample multiplied by its weight in `sample_weights`.
+    /// 
+    /// The mean is taken with respect to the weights, dividing by their sum rather than the number of samples.
+    /// Taking the mean fails for an empty batch, or for weights summing to zero.
+    fn lf_batch_loss_weighted(&self, y_true: &[[F; Y_TRUE]], y_est: &[[F; Y_EST]], sample_weights: &[F], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>;
+    fn lf_batch_loss_weighted_flat(&self, y_true: &[F], y_est: &[F], sample_weights: &[F], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>;
+}
+
+impl<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> BatchLossFunction<F, Y_TRUE, Y_EST, L> for T
+where
+    T: LossFunction<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_batch_loss(&self, y_true: &[[F; Y_TRUE]], y_est: &[[F; Y_EST]], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>
+    {
+        batch_loss(self, y_true, y_est, None, reduction)
+    }
+    fn lf_batch_loss_grad(&self, y_true: &[[F; Y_TRUE]], y_est: &[[F; Y_EST]]) -> Result<Vec<[[F; Y_EST]; L]>, LossFunctionError>
+    {
+        LossFunctionError::check_batch_len(y_est.len(), y_true.len())?;
+
+        Ok(y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.lf_loss_grad(y_true, y_est))
+            .collect())
+    }
+
+    fn lf_batch_loss_flat(&self, y_true: &[F], y_est: &[F], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>
+    {
+        let (y_true, y_est) = batch_chunks(y_true, y_est)?;
+
+        self.lf_batch_loss(&y_true, &y_est, reduction)
+    }
+    fn lf_batch_loss_grad_flat(&self, y_true: &[F], y_est: &[F]) -> Result<Vec<[[F; Y_EST]; L]>, LossFunctionError>
+    {
+        let (y_true, y_est) = batch_chunks(y_true, y_est)?;
+
+        self.lf_batch_loss_grad(&y_true, &y_est)
+    }
+
+    fn lf_batch_loss_weighted(&self, y_true: &[[F; Y_TRUE]], y_est: &[[F; Y_EST]], sample_weights: &[F], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>
+    {
+        batch_loss(self, y_true, y_est, Some(sample_weights), reduction)
+    }
+    fn lf_batch_loss_weighted_flat(&self, y_true: &[F], y_est: &[F], sample_weights: &[F], reduction: Reduction) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>
+    {
+        let (y_true, y_est) = batch_chunks(y_true, y_est)?;
+
+        self.lf_batch_loss_weighted(&y_true, &y_est, sample_weights, reduction)
+    }
+}
+
+fn batch_loss<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(
+    lf: &T,
+    y_true: &[[F; Y_TRUE]],
+    y_est: &[[F; Y_EST]],
+    sample_weights: Option<&[F]>,
+    reduction: Reduction
+) -> Result<BatchLoss<F, Y_EST, L>, LossFunctionError>
+where
+    T: LossFunction<F, Y_TRUE, Y_EST, L> + ?Sized,
+    F: Float
+{
+    LossFunctionError::check_batch_len(y_est.len(), y_true.len())?;
+    if let Some(sample_weights) = sample_weights
+    {
+        LossFunctionError::check_batch_len(y_est.len(), sample_weights.len())?;
+    }
+    if reduction == Reduction::Mean && y_est.is_empty()
+    {
+        return Err(LossFunctionError::EmptyBatch)
+    }
+
+    let mut losses = Vec::with_capacity(y_est.len());
+    let mut loss = [F::zero(); L];
+    let mut loss_grad = [[F::zero(); Y_EST]; L];
+    let mut weight_sum = F::zero();
+
+    for (k, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+    {
+        let w = sample_weights.map(|sample_weights| sample_weights[k]);
+
+        if reduction == Reduction::None
+        {
+            let l = lf.lf_loss(y_true, y_est);
+            losses.push(match w
+            {
+                Some(w) => l.map(|l| l*w),
+                None => l
+            });
+            continue
+        }
+
+        let (mut l, mut l_grad) = lf.lf_loss_and_grad(y_true, y_est);
+        if let Some(w) = w
+        {
+            l = l.map(|l| l*w);
+            l_grad = l_grad.map(|l_grad| l_grad.map(|l_grad| l_grad*w));
+            weight_sum = weight_sum + w;
+        }
+        loss = loss.comap(l, |loss, l| loss + l);
+        loss_grad = loss_grad.comap(l_grad, |loss_grad, l_grad| loss_grad.comap(l_grad, |loss_grad, l_grad| loss_grad + l_grad));
+        losses.push(l);
+    }
+
+    let (loss, loss_grad) = match reduction
+    {
+        Reduction::Mean => {
+            let len_inv = match sample_weights
+            {
+                Some(_) if weight_sum.is_zero() => return Err(LossFunctionError::ZeroWeightSum),
+                Some(_) => weight_sum.recip(),
+                None => f!(y_est.len(); F).recip()
+            };
+            (
+                Some(loss.map(|loss| loss*len_inv)),
+                Some(loss_grad.map(|loss_grad| loss_grad.map(|loss_grad| loss_grad*len_inv)))
+            )
+        },
+        Reduction::Sum => (Some(loss), Some(loss_grad)),
+        Reduction::None => (None, None)
+    };
+
+    Ok(BatchLoss {
+        losses,
+        loss,
+        loss_grad
+    })
+}
+
+fn batch_chunks<F, const Y_TRUE: usize, const Y_EST: usize>(y_true: &[F], y_est: &[F]) -> Result<(Vec<[F; Y_TRUE]>, Vec<[F; Y_EST]>), LossFunctionError>
+where
+    F: Copy
+{
+    let n = y_est.len()/Y_EST.max(1);
+    LossFunctionError::check_y_est_len(n*Y_EST, y_est.len())?;
+    LossFunctionError::check_y_true_len(n*Y_TRUE, y_true.len())?;
+
+    Ok((
+        y_true.chunks_exact(Y_TRUE.max(1))
+            .take(n)
+            .map(|y_true| <[F; Y_TRUE]>::fill(|i| y_true[i]))
+            .collect(),
+        y_est.chunks_exact(Y_EST.max(1))
+            .take(n)
+            .map(|y_est| <[F; Y_EST]>::fill(|i| y_est[i]))
+            .collect()
+    ))
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{MeanSquareError, SquareErrorLoss, LossFunction, LossFunctionError};
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let y_est = [[1.5, 2.0], [2.0, 4.5], [5.0, 5.0]];
+
+        let batch = MeanSquareError.lf_batch_loss(&y_true, &y_est, Reduction::Mean).unwrap();
+        println!("{:?}", batch);
+
+        let l = y_true.comap(y_est, |y_true, y_est| MeanSquareError.lf_loss(y_true, y_est)[0]);
+        assert_eq!(batch.losses, l.map(|l| [l]));
+        assert!((batch.loss.unwrap()[0] - l.sum()/3.0).abs() < 1e-12);
+
+        let batch_flat = MeanSquareError.lf_batch_loss_flat(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[1.5, 2.0, 2.0, 4.5, 5.0, 5.0], Reduction::Mean).unwrap();
+        assert_eq!(batch_flat, batch);
+
+        let batch = SquareErrorLoss.lf_batch_loss(&y_true, &y_est, Reduction::None).unwrap();
+        assert_eq!(batch.loss, None);
+        assert_eq!(batch.loss_grad, None);
+
+        let batch = SquareErrorLoss.lf_batch_loss(&y_true, &y_est, Reduction::Sum).unwrap();
+        assert_eq!(batch.loss, Some([1.25, 0.25 + 1.0]));
+        assert_eq!(batch.loss_grad, Some([[1.0 - 2.0, 0.0], [0.0, 1.0 - 2.0]]));
+
+        assert_eq!(
+            MeanSquareError.lf_batch_loss(&y_true[1..], &y_est, Reduction::Mean),
+            Err(LossFunctionError::BatchLength {expected: 3, found: 2})
+        );
+        assert_eq!(
+            <MeanSquareError as BatchLossFunction<f64, 2, 2>>::lf_batch_loss_flat(&MeanSquareError, &[1.0, 2.0, 3.0], &[1.5, 2.0, 2.0], Reduction::Mean),
+            Err(LossFunctionError::YEstLength {expected: 2, found: 3})
+        );
+
+        let batch = MeanSquareError.lf_batch_loss(&y_true, &y_est, Reduction::Mean).unwrap();
+        let batch_weighted = MeanSquareError.lf_batch_loss_weighted(&y_true, &y_est, &[1.0; 3], Reduction::Mean).unwrap();
+        assert_eq!(batch_weighted, batch);
+
+        let batch_weighted = MeanSquareError.lf_batch_loss_weighted(&y_true, &y_est, &[2.0, 0.0, 1.0], Reduction::Mean).unwrap();
+        assert_eq!(batch_weighted.losses, vec![[2.0*l[0]], [0.0], [l[2]]]);
+        assert!((batch_weighted.loss.unwrap()[0] - (2.0*l[0] + l[2])/3.0).abs() < 1e-12);
+
+        assert_eq!(
+            MeanSquareError.lf_batch_loss_weighted(&y_true, &y_est, &[1.0; 2], Reduction::Sum),
+            Err(LossFunctionError::BatchLength {expected: 3, found: 2})
+        );
+
+        assert_eq!(
+            MeanSquareError.lf_batch_loss(&[[0.0; 2]; 0], &[[0.0; 2]; 0], Reduction::Mean),
+            Err(LossFunctionError::EmptyBatch)
+        );
+        assert_eq!(
+            MeanSquareError.lf_batch_loss_weighted(&y_true, &y_est, &[1.0, -1.0, 0.0], Reduction::Mean),
+            Err(LossFunctionError::ZeroWeightSum)
+        );
+        assert!(MeanSquareError.lf_batch_loss(&[[0.0; 2]; 0], &[[0.0; 2]; 0], Reduction::Sum).is_ok());
+    }
+}
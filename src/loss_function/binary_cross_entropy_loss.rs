@@ -5,29 +5,187 @@ use num_identities_const::ZeroConst;
 
 use super::*;
 
+/// Binary cross-entropy of the probability estimates `y_est`.
+/// 
+/// The estimates are clamped to `[epsilon, 1 - epsilon]` before use, with `epsilon` being [DEFAULT_EPSILON](BinaryCrossEntropyLoss::DEFAULT_EPSILON),
+/// so that estimates of exactly zero or one give a finite loss and gradient.
+/// Within the clamped regions, the gradient is the one at the clamped estimate.
+/// Since the loss is flat there, with a kink at either end of the clamp, it is neither convex nor differentiable when `epsilon > 0`.
+/// Use [with_epsilon](BinaryCrossEntropyLoss::with_epsilon) to clamp by another `epsilon`.
 #[derive(Clone, Copy, Debug)]
 pub struct BinaryCrossEntropyLoss;
 
+impl BinaryCrossEntropyLoss
+{
+    pub const DEFAULT_EPSILON: f64 = 1e-7;
+
+    pub fn with_epsilon(self, epsilon: f64) -> WithEpsilon<Self>
+    {
+        WithEpsilon::new(self, epsilon)
+    }
+}
+
+/// Clamps the probability `y_est` to `[epsilon, 1 - epsilon]`, so that its logarithms are finite.
+pub(crate) fn clamp_probability<F>(y_est: F, epsilon: f64) -> F
+where
+    F: Float
+{
+    let epsilon = f!(epsilon; F);
+
+    y_est.max(epsilon).min(F::one() - epsilon)
+}
+
+/// A loss which clamps its estimates to `[epsilon, 1 - epsilon]` instead of its default range.
+/// 
+/// Build it with the `with_epsilon` method of the loss.
+#[derive(Clone, Copy, Debug)]
+pub struct WithEpsilon<L>
+{
+    pub loss: L,
+    pub epsilon: f64
+}
+
+impl<L> WithEpsilon<L>
+{
+    pub fn new(loss: L, epsilon: f64) -> Self
+    {
+        Self {
+            loss,
+            epsilon
+        }
+    }
+
+    fn clamp<F>(&self, y_est: F) -> F
+    where
+        F: Float
+    {
+        clamp_probability(y_est, self.epsilon)
+    }
+}
+
 impl<F, const Y: usize> LossFunction<F, Y, Y> for BinaryCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_loss(y_true, y_est)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_loss_grad(y_true, y_est)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_loss_and_grad(y_true, y_est)
+    }
+}
+
+impl<F, const Y: usize> LossFunctionHessian<F, Y, Y> for BinaryCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y], y_est: [F; Y]) -> [[[F; Y]; Y]; 1]
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_loss_hessian(y_true, y_est)
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_loss_hessian_diagonal(y_true, y_est)
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for BinaryCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_weighted_loss(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_weighted_loss_grad(y_true, y_est, weights)
+    }
+}
+
+impl<F> DynLossFunction<F> for BinaryCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_dyn_loss(y_true, y_est, loss)
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_dyn_loss_grad(y_true, y_est, loss_grad)
+    }
+}
+
+impl LossProperties for BinaryCrossEntropyLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_is_convex()
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_lipschitz()
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_smoothness()
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        self.with_epsilon(Self::DEFAULT_EPSILON).lf_bound()
+    }
+}
+
+impl WithEpsilon<BinaryCrossEntropyLoss>
+{
+    fn binary_cross_entropy<F>(&self, y_true: F, y_est: F) -> F
+    where
+        F: Float
+    {
+        let y_est = self.clamp(y_est);
+
+        -match (y_true.is_zero(), y_true.is_one())
+        {
+            (false, false) => y_true*y_est.ln() + (F::one() - y_true)*(-y_est).ln_1p(),
+            (false, true) => y_true*y_est.ln(),
+            (true, false) => (F::one() - y_true)*(-y_est).ln_1p(),
+            (true, true) => F::zero()
+        }
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for WithEpsilon<BinaryCrossEntropyLoss>
 where
     F: Float + AddAssign + ZeroConst
 {
     fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
     {
         let len_inv = f!(Y; F).recip();
-        
+
         [
-            -len_inv*y_true.comap(y_est, |y_true, y_est| match (y_true.is_zero(), y_true.is_one())
-                {
-                    (false, false) => y_true*y_est.ln() + (F::one() - y_true)*(-y_est).ln_1p(),
-                    (false, true) => y_true*y_est.ln(),
-                    (true, false) => (F::one() - y_true)*(-y_est).ln_1p(),
-                    (true, true) => F::zero()
-                }).sum()
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.binary_cross_entropy(y_true, y_est))
+                .sum()
         ]
     }
     fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
     {
+        let y_est = y_est.map(|y_est| self.clamp(y_est));
         let len_inv = f!(Y; F).recip();
 
         [
@@ -40,18 +198,176 @@ where
                 })
         ]
     }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let y_est = y_est.map(|y_est| self.clamp(y_est));
+        let len_inv = f!(Y; F).recip();
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| {
+            let y_true_neg = F::one() - y_true;
+            let y_est_neg = F::one() - y_est;
+            match (y_true.is_zero(), y_true.is_one())
+            {
+                (false, false) => (y_true*y_est.ln() + y_true_neg*(-y_est).ln_1p(), y_true/y_est - y_true_neg/y_est_neg),
+                (false, true) => (y_true*y_est.ln(), y_true/y_est),
+                (true, false) => (y_true_neg*(-y_est).ln_1p(), -y_true_neg/y_est_neg),
+                (true, true) => (F::zero(), F::zero())
+            }
+        });
+
+        (
+            [-len_inv*l_and_grad.map(|(l, _)| l).sum()],
+            [l_and_grad.map(|(_, l_grad)| -len_inv*l_grad)]
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunctionHessian<F, Y, Y> for WithEpsilon<BinaryCrossEntropyLoss>
+where
+    F: Float + AddAssign + ZeroConst + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y], y_est: [F; Y]) -> [[[F; Y]; Y]; 1]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let y_est = y_est.map(|y_est| self.clamp(y_est));
+        let len_inv = f!(Y; F).recip();
+
+        [
+            y_true.comap(y_est, |y_true, y_est| len_inv*match (y_true.is_zero(), y_true.is_one())
+                {
+                    (false, false) => y_true/(y_est*y_est) + (F::one() - y_true)/((F::one() - y_est)*(F::one() - y_est)),
+                    (false, true) => y_true/(y_est*y_est),
+                    (true, false) => (F::one() - y_true)/((F::one() - y_est)*(F::one() - y_est)),
+                    (true, true) => F::zero()
+                })
+        ]
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for WithEpsilon<BinaryCrossEntropyLoss>
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.binary_cross_entropy(y_true, y_est))
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for WithEpsilon<BinaryCrossEntropyLoss>
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.binary_cross_entropy(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            let y_est = self.clamp(y_est);
+            *loss_grad = -len_inv*match (y_true.is_zero(), y_true.is_one())
+            {
+                (false, false) => y_true/y_est - (F::one() - y_true)/(F::one() - y_est),
+                (false, true) => y_true/y_est,
+                (true, false) => -(F::one() - y_true)/(F::one() - y_est),
+                (true, true) => F::zero()
+            };
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for WithEpsilon<BinaryCrossEntropyLoss>
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        // The loss is flat outside the clamp, so its slope jumps from zero to -1/epsilon at its ends
+        self.epsilon <= 0.0
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.epsilon <= 0.0
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        (self.epsilon > 0.0).then(|| self.epsilon.recip())
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        (self.epsilon > 0.0).then(|| self.epsilon.powi(-2))
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        (self.epsilon > 0.0).then(|| -self.epsilon.ln())
+    }
 }
 
 #[cfg(test)]
 mod test
 {
     use crate::tests as t;
+    use crate::{LossFunction, LossProperties};
+
     use super::BinaryCrossEntropyLoss as LF;
 
     #[test]
     fn test()
     {
         t::test(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.9, 0.1, 0.9]);
+        t::test_dyn(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.9, 0.1, 0.9]);
+        t::test_gradient(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.9, 0.1, 0.9]);
+        t::test_hessian(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.9, 0.1, 0.9]);
         t::graph_2d(LF, [0.2, 0.0], 0.001..1.0);
+
+        let (l, l_grad) = LF.lf_loss_and_grad([1.0, 0.0], [0.0, 1.0]);
+        assert!(l[0].is_finite());
+        assert!(l_grad[0].into_iter().all(f64::is_finite));
+
+        t::test(LF.with_epsilon(1e-3), [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.9, 0.1, 0.9]);
+        t::test_dyn(LF.with_epsilon(1e-3), [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.9, 0.1, 0.9]);
+
+        let [l] = LF.with_epsilon(0.25).lf_loss([1.0], [0.0]);
+        assert_eq!(l, -0.25f64.ln());
+        assert_eq!(LF.with_epsilon(0.0).lf_loss([1.0], [0.0]), [f64::INFINITY]);
+
+        // Outside the clamp, the loss is flat, so only the unclamped loss is convex
+        assert_eq!(LF.with_epsilon(1e-3).lf_loss([1.0], [5e-4]), LF.with_epsilon(1e-3).lf_loss([1.0], [1e-3]));
+        assert_eq!(LF.with_epsilon(1e-3).lf_loss([0.0], [0.9995]), LF.with_epsilon(1e-3).lf_loss([0.0], [0.999]));
+        assert!(!LF.with_epsilon(1e-3).lf_is_convex() && !LF.lf_is_convex());
+        assert!(LF.with_epsilon(0.0).lf_is_convex() && LF.with_epsilon(0.0).lf_is_differentiable());
     }
 }
\ No newline at end of file
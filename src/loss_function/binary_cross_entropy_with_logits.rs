@@ -0,0 +1,181 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Binary cross-entropy of the sigmoid of the scores `y_est`.
+/// 
+/// The loss is computed as `max(y_est, 0) - y_est*y_true + ln(1 + exp(-|y_est|))`, which never overflows, and the gradient is `sigmoid(y_est) - y_true`.
+#[derive(Clone, Copy, Debug)]
+pub struct BinaryCrossEntropyWithLogits;
+
+fn binary_cross_entropy_with_logits<F>(y_true: F, y_est: F) -> F
+where
+    F: Float
+{
+    softplus(y_est) - y_est*y_true
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for BinaryCrossEntropyWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, binary_cross_entropy_with_logits)
+                .sum()
+        ]
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            y_true.comap(y_est, |y_true, y_est| len_inv*(sigmoid(y_est) - y_true))
+        ]
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let len_inv = f!(Y; F).recip();
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| (
+            binary_cross_entropy_with_logits(y_true, y_est),
+            len_inv*(sigmoid(y_est) - y_true)
+        ));
+
+        (
+            [len_inv*l_and_grad.map(|(l, _)| l).sum()],
+            [l_and_grad.map(|(_, l_grad)| l_grad)]
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunctionHessian<F, Y, Y> for BinaryCrossEntropyWithLogits
+where
+    F: Float + AddAssign + ZeroConst + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y], y_est: [F; Y]) -> [[[F; Y]; Y]; 1]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, _y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            y_est.map(|y_est| {
+                let sigmoid = sigmoid(y_est);
+                len_inv*sigmoid*(F::one() - sigmoid)
+            })
+        ]
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for BinaryCrossEntropyWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, binary_cross_entropy_with_logits)
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for BinaryCrossEntropyWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| binary_cross_entropy_with_logits(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss_grad = len_inv*(sigmoid(y_est) - y_true);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for BinaryCrossEntropyWithLogits
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(0.25)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, BinaryCrossEntropyLoss, LossFunction};
+    use super::BinaryCrossEntropyWithLogits as LF;
+
+    #[test]
+    fn test()
+    {
+        t::test(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, 800.0]);
+        t::test_dyn(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, 800.0]);
+        t::test_gradient(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, 800.0]);
+        t::test_hessian(LF, [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, -3.0]);
+        t::graph_2d(LF, [0.2, 0.0], -4.0..4.0);
+
+        let y_true = [1.0, 0.0, 0.3];
+        let y_est = [2.2, -1.0, 0.5];
+        let l = LF.lf_loss(y_true, y_est);
+        let l_prob = BinaryCrossEntropyLoss.with_epsilon(0.0).lf_loss(y_true, y_est.map(|y_est: f64| (1.0 + (-y_est).exp()).recip()));
+        assert!((l[0] - l_prob[0]).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,209 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Focal loss of the probability estimates `y_est`, which down-weights well-classified samples by `(1 - p_t)^gamma`.
+/// 
+/// Positive samples are weighted by `alpha` and negative samples by `1 - alpha`.
+/// With `gamma = 0` and `alpha = 0.5`, this is half the [BinaryCrossEntropyLoss](BinaryCrossEntropyLoss), and the estimates are clamped the same way.
+#[derive(Clone, Copy, Debug)]
+pub struct BinaryFocalLoss
+{
+    pub gamma: f64,
+    pub alpha: f64
+}
+
+impl BinaryFocalLoss
+{
+    pub fn new(gamma: f64, alpha: f64) -> Self
+    {
+        Self {
+            gamma,
+            alpha
+        }
+    }
+
+    fn focal<F>(&self, y_true: F, y_est: F) -> F
+    where
+        F: Float
+    {
+        let one = F::one();
+        let gamma = f!(self.gamma; F);
+        let alpha = f!(self.alpha; F);
+
+        let y_est = clamp_probability(y_est, BinaryCrossEntropyLoss::DEFAULT_EPSILON);
+
+        -alpha*y_true*(one - y_est).powf(gamma)*y_est.ln()
+            - (one - alpha)*(one - y_true)*y_est.powf(gamma)*(-y_est).ln_1p()
+    }
+
+    fn focal_and_grad<F>(&self, y_true: F, y_est: F) -> (F, F)
+    where
+        F: Float
+    {
+        let one = F::one();
+        let gamma = f!(self.gamma; F);
+        let alpha = f!(self.alpha; F);
+
+        let y_est = clamp_probability(y_est, BinaryCrossEntropyLoss::DEFAULT_EPSILON);
+        let y_est_neg = one - y_est;
+
+        let ln = y_est.ln();
+        let ln_neg = (-y_est).ln_1p();
+        let pow = y_est.powf(gamma);
+        let pow_neg = y_est_neg.powf(gamma);
+        let pos = alpha*y_true;
+        let neg = (one - alpha)*(one - y_true);
+
+        (
+            -pos*pow_neg*ln - neg*pow*ln_neg,
+            -pos*(pow_neg/y_est - gamma*pow_neg/y_est_neg*ln) + neg*(pow/y_est_neg - gamma*pow/y_est*ln_neg)
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for BinaryFocalLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.focal(y_true, y_est))
+                .sum()
+        ]
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            y_true.comap(y_est, |y_true, y_est| len_inv*self.focal_and_grad(y_true, y_est).1)
+        ]
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let len_inv = f!(Y; F).recip();
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| self.focal_and_grad(y_true, y_est));
+
+        (
+            [len_inv*l_and_grad.map(|(l, _)| l).sum()],
+            [l_and_grad.map(|(_, l_grad)| len_inv*l_grad)]
+        )
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for BinaryFocalLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.focal(y_true, y_est))
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for BinaryFocalLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.focal(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss_grad = len_inv*self.focal_and_grad(y_true, y_est).1;
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for BinaryFocalLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, BinaryCrossEntropyLoss, LossFunction};
+    use super::BinaryFocalLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let gamma = 2.0;
+        let alpha = 0.25;
+
+        t::test(LF::new(gamma, alpha), [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.6, 0.3, 0.99]);
+        t::test_dyn(LF::new(gamma, alpha), [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.6, 0.3, 0.99]);
+        t::test_gradient(LF::new(gamma, alpha), [1.0, 0.0, 1.0, 0.0, 1.0], [0.9, 0.1, 0.6, 0.3, 0.99]);
+        t::graph_2d(LF::new(gamma, alpha), [0.2, 0.0], 0.001..0.999);
+
+        let y_true = [1.0, 0.0, 1.0, 0.0, 0.4];
+        let y_est = [0.9, 0.1, 0.6, 0.3, 0.5];
+        let (l, l_grad) = LF::new(0.0, 0.5).lf_loss_and_grad(y_true, y_est);
+        let (l_ce, l_grad_ce) = BinaryCrossEntropyLoss.lf_loss_and_grad(y_true, y_est);
+        assert!((2.0*l[0] - l_ce[0]).abs() < 1e-12);
+        assert!(l_grad[0].into_iter().zip(l_grad_ce[0]).all(|(a, b)| (2.0*a - b).abs() < 1e-12));
+
+        // Clamped like the binary cross-entropy, so that certain estimates stay finite
+        let (l, l_grad) = LF::new(gamma, alpha).lf_loss_and_grad([1.0, 0.0, 1.0, 0.0], [0.0, 1.0, 1.0, 0.0]);
+        assert!(l[0].is_finite() && l_grad[0].into_iter().all(f64::is_finite));
+    }
+}
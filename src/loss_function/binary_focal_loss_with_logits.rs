@@ -0,0 +1,184 @@
+use std::{f64::consts::E, ops::AddAssign};
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Focal loss of the sigmoid of the scores `y_est`.
+/// 
+/// Equivalent to [BinaryFocalLoss](BinaryFocalLoss) of `sigmoid(y_est)`, but with the logarithms computed directly from the scores, so that confident scores don't overflow.
+#[derive(Clone, Copy, Debug)]
+pub struct BinaryFocalLossWithLogits
+{
+    pub gamma: f64,
+    pub alpha: f64
+}
+
+impl BinaryFocalLossWithLogits
+{
+    pub fn new(gamma: f64, alpha: f64) -> Self
+    {
+        Self {
+            gamma,
+            alpha
+        }
+    }
+
+    fn focal_and_grad<F>(&self, y_true: F, y_est: F) -> (F, F)
+    where
+        F: Float
+    {
+        let one = F::one();
+        let gamma = f!(self.gamma; F);
+        let alpha = f!(self.alpha; F);
+
+        let ln_p = -softplus(-y_est);
+        let ln_p_neg = -softplus(y_est);
+        let p = ln_p.exp();
+        let p_neg = ln_p_neg.exp();
+
+        let pos = alpha*y_true*p_neg.powf(gamma);
+        let neg = (one - alpha)*(one - y_true)*p.powf(gamma);
+
+        (
+            -pos*ln_p - neg*ln_p_neg,
+            -pos*(p_neg - gamma*p*ln_p) - neg*(gamma*p_neg*ln_p_neg - p)
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for BinaryFocalLossWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        self.lf_loss_and_grad(y_true, y_est).0
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_and_grad(y_true, y_est).1
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let len_inv = f!(Y; F).recip();
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| self.focal_and_grad(y_true, y_est));
+
+        (
+            [len_inv*l_and_grad.map(|(l, _)| l).sum()],
+            [l_and_grad.map(|(_, l_grad)| len_inv*l_grad)]
+        )
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for BinaryFocalLossWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.focal_and_grad(y_true, y_est).0)
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for BinaryFocalLossWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.focal_and_grad(y_true, y_est).0)
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss_grad = len_inv*self.focal_and_grad(y_true, y_est).1;
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for BinaryFocalLossWithLogits
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.gamma == 0.0
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.alpha.max(1.0 - self.alpha)*(1.0 + self.gamma/E))
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        (self.gamma == 0.0).then(|| self.alpha.max(1.0 - self.alpha)*0.25)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, BinaryCrossEntropyWithLogits, BinaryFocalLoss, LossFunction};
+    use super::BinaryFocalLossWithLogits as LF;
+
+    #[test]
+    fn test()
+    {
+        let gamma = 2.0;
+        let alpha = 0.25;
+
+        t::test(LF::new(gamma, alpha), [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, 800.0]);
+        t::test_dyn(LF::new(gamma, alpha), [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, 800.0]);
+        t::test_gradient(LF::new(gamma, alpha), [1.0, 0.0, 1.0, 0.0, 1.0], [2.2, -2.2, 0.5, 0.1, 800.0]);
+        t::graph_2d(LF::new(gamma, alpha), [0.2, 0.0], -4.0..4.0);
+
+        let y_true = [1.0, 0.0, 1.0, 0.0, 0.4];
+        let y_est = [2.2, -2.2, 0.5, 0.1, -1.0];
+
+        let l = LF::new(gamma, alpha).lf_loss(y_true, y_est);
+        let l_prob = BinaryFocalLoss::new(gamma, alpha).lf_loss(y_true, y_est.map(|y_est: f64| (1.0 + (-y_est).exp()).recip()));
+        assert!((l[0] - l_prob[0]).abs() < 1e-12);
+
+        let (l, l_grad) = LF::new(0.0, 0.5).lf_loss_and_grad(y_true, y_est);
+        let (l_ce, l_grad_ce) = BinaryCrossEntropyWithLogits.lf_loss_and_grad(y_true, y_est);
+        assert!((2.0*l[0] - l_ce[0]).abs() < 1e-12);
+        assert!(l_grad[0].into_iter().zip(l_grad_ce[0]).all(|(a, b)| (2.0*a - b).abs() < 1e-12));
+    }
+}
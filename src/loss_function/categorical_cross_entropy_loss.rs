@@ -0,0 +1,194 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Cross-entropy between the target distribution `y_true` and the softmax of the logits `y_est`.
+/// 
+/// The softmax is never computed explicitly for the loss, which uses log-sum-exp instead, so large logits don't overflow.
+/// For normalized targets, the gradient is `softmax(y_est) - y_true`.
+#[derive(Clone, Copy, Debug)]
+pub struct CategoricalCrossEntropyLoss;
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for CategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.map(|y_est| (y_est - max).exp()).sum().ln();
+
+        [
+            y_true.comap(y_est, |y_true, y_est| y_true*(lse - y_est))
+                .sum()
+        ]
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum_inv = exp.sum().recip();
+        let y_true_sum = y_true.sum();
+
+        [
+            exp.comap(y_true, |exp, y_true| y_true_sum*exp*exp_sum_inv - y_true)
+        ]
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum = exp.sum();
+        let exp_sum_inv = exp_sum.recip();
+        let lse = max + exp_sum.ln();
+        let y_true_sum = y_true.sum();
+
+        (
+            [y_true.comap(y_est, |y_true, y_est| y_true*(lse - y_est)).sum()],
+            [exp.comap(y_true, |exp, y_true| y_true_sum*exp*exp_sum_inv - y_true)]
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunctionHessian<F, Y, Y> for CategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y], y_est: [F; Y]) -> [[[F; Y]; Y]; 1]
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum_inv = exp.sum().recip();
+        let p = exp.map(|exp| exp*exp_sum_inv);
+        let y_true_sum = y_true.sum();
+
+        [
+            <[[F; Y]; Y]>::fill(|i| <[F; Y]>::fill(|j| if i == j
+                {
+                    y_true_sum*p[i]*(F::one() - p[i])
+                }
+                else
+                {
+                    -y_true_sum*p[i]*p[j]
+                }
+            ))
+        ]
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum_inv = exp.sum().recip();
+        let y_true_sum = y_true.sum();
+
+        [
+            exp.map(|exp| {
+                let p = exp*exp_sum_inv;
+                y_true_sum*p*(F::one() - p)
+            })
+        ]
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for CategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        self.lf_loss(weight_targets(y_true, weights), y_est)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(weight_targets(y_true, weights), y_est)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        self.lf_loss_and_grad(weight_targets(y_true, weights), y_est)
+    }
+}
+
+impl<F> DynLossFunction<F> for CategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let max = y_est.iter().copied().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.iter().fold(F::zero(), |sum, &y_est| sum + (y_est - max).exp()).ln();
+
+        loss[0] = y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| y_true*(lse - y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let max = y_est.iter().copied().fold(F::neg_infinity(), F::max);
+        for (loss_grad, &y_est) in loss_grad.iter_mut().zip(y_est)
+        {
+            *loss_grad = (y_est - max).exp();
+        }
+        let exp_sum_inv = loss_grad.iter().fold(F::zero(), |a, &b| a + b).recip();
+        let y_true_sum = y_true.iter().fold(F::zero(), |a, &b| a + b);
+
+        for (loss_grad, &y_true) in loss_grad.iter_mut().zip(y_true)
+        {
+            *loss_grad = y_true_sum*(*loss_grad)*exp_sum_inv - y_true;
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for CategoricalCrossEntropyLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(0.25)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+    use super::CategoricalCrossEntropyLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        t::test(LF, [0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_dyn(LF, [0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_gradient(LF, [0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_hessian(LF, [0.1, 0.6, 0.1, 0.1, 0.1], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::graph_2d(LF, [0.2, 0.8], -1.0..1.0);
+    }
+}
@@ -0,0 +1,74 @@
+use num::Float;
+
+use super::*;
+
+/// A loss function with fixed class weights, one for each component of the estimate.
+#[derive(Clone, Copy, Debug)]
+pub struct ClassWeighted<T, const Y: usize>
+{
+    pub loss: T,
+    pub weights: [f64; Y]
+}
+
+impl<T, const Y: usize> ClassWeighted<T, Y>
+{
+    pub fn new(loss: T, weights: [f64; Y]) -> Self
+    {
+        Self {
+            loss,
+            weights
+        }
+    }
+}
+
+impl<T, F, const Y_TRUE: usize, const Y: usize, const L: usize> LossFunction<F, Y_TRUE, Y, L> for ClassWeighted<T, Y>
+where
+    T: WeightedLossFunction<F, Y_TRUE, Y, L>,
+    F: Float
+{
+    fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y]) -> [F; L]
+    {
+        self.loss.lf_weighted_loss(y_true, y_est, self.weights.map(|w| f!(w)))
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y]) -> [[F; Y]; L]
+    {
+        self.loss.lf_weighted_loss_grad(y_true, y_est, self.weights.map(|w| f!(w)))
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y]) -> ([F; L], [[F; Y]; L])
+    {
+        self.loss.lf_weighted_loss_and_grad(y_true, y_est, self.weights.map(|w| f!(w)))
+    }
+}
+
+impl<T, const Y: usize> LossProperties for ClassWeighted<T, Y>
+where
+    T: LossProperties
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.weights.into_iter().all(|w| w >= 0.0) && self.loss.lf_is_convex()
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.loss.lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.weights.into_iter().map(f64::abs).fold(0.0, f64::max)*self.loss.lf_lipschitz()?)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(self.weights.into_iter().map(f64::abs).fold(0.0, f64::max)*self.loss.lf_smoothness()?)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        if self.weights.into_iter().all(|w| w >= 0.0)
+        {
+            Some(self.weights.into_iter().fold(0.0, f64::max)*self.loss.lf_bound()?)
+        }
+        else
+        {
+            None
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use num::Float;
+
+use super::*;
+
+/// The link function which leaves the estimate unchanged.
+#[derive(Clone, Copy, Debug)]
+pub struct IdentityLink;
+
+impl LinkCombinators for IdentityLink
+{
+
+}
+
+impl<F, const X: usize> LinkFunction<F, X> for IdentityLink
+where
+    F: Float
+{
+    fn link(&self, x: [F; X]) -> [F; X]
+    {
+        x
+    }
+    fn link_jacobian(&self, _x: [F; X]) -> [[F; X]; X]
+    {
+        <[[F; X]; X]>::fill(|i| <[F; X]>::fill(|j| if i == j {F::one()} else {F::zero()}))
+    }
+}
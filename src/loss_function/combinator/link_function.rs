@@ -0,0 +1,26 @@
+/// A function mapping a raw estimate onto the domain of a loss function, such as scores onto probabilities.
+/// 
+/// `link_jacobian(x)[i][j]` is the derivative of `link(x)[i]` with respect to `x[j]`.
+pub trait LinkFunction<F, const X: usize, const Y: usize = X>
+{
+    fn link(&self, x: [F; X]) -> [F; Y];
+    fn link_jacobian(&self, x: [F; X]) -> [[F; X]; Y];
+
+    /// Computes both the link and its jacobian, sharing any intermediate terms between them.
+    fn link_and_jacobian(&self, x: [F; X]) -> ([F; Y], [[F; X]; Y])
+    where
+        F: Copy
+    {
+        (self.link(x), self.link_jacobian(x))
+    }
+}
+
+/// Methods for building losses on a link function, such as `SigmoidLink.then(BinaryCrossEntropyLoss)`.
+pub trait LinkCombinators: Sized
+{
+    /// Maps the estimate through this link before it is passed on to `loss`.
+    fn then<T, const Y: usize>(self, loss: T) -> LinkedLoss<Self, T, Y>
+    {
+        LinkedLoss::new(self, loss)
+    }
+}
@@ -0,0 +1,96 @@
+use num::Float;
+
+use super::*;
+
+/// A loss function applied to the output of a [link function](LinkFunction), with the gradient passed back through the link's jacobian.
+/// 
+/// `Y` is the length of the link's output, which is the estimate seen by the loss.
+#[derive(Clone, Copy, Debug)]
+pub struct LinkedLoss<G, T, const Y: usize>
+{
+    pub link: G,
+    pub loss: T
+}
+
+impl<G, T, const Y: usize> LinkedLoss<G, T, Y>
+{
+    pub fn new(link: G, loss: T) -> Self
+    {
+        Self {
+            link,
+            loss
+        }
+    }
+}
+
+fn chain<F, const X: usize, const Y: usize, const L: usize>(l_grad: [[F; Y]; L], jacobian: [[F; X]; Y]) -> [[F; X]; L]
+where
+    F: Float
+{
+    l_grad.map(|l_grad| <[F; X]>::fill(|j| l_grad.into_iter()
+        .zip(jacobian)
+        .fold(F::zero(), |a, (l_grad, jacobian)| a + l_grad*jacobian[j])
+    ))
+}
+
+impl<G, T, F, const Y_TRUE: usize, const X: usize, const Y: usize, const L: usize> LossFunction<F, Y_TRUE, X, L> for LinkedLoss<G, T, Y>
+where
+    G: LinkFunction<F, X, Y>,
+    T: LossFunction<F, Y_TRUE, Y, L>,
+    F: Float
+{
+    fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; X]) -> [F; L]
+    {
+        self.loss.lf_loss(y_true, self.link.link(y_est))
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; X]) -> [[F; X]; L]
+    {
+        let (y_link, jacobian) = self.link.link_and_jacobian(y_est);
+
+        chain(self.loss.lf_loss_grad(y_true, y_link), jacobian)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; X]) -> ([F; L], [[F; X]; L])
+    {
+        let (y_link, jacobian) = self.link.link_and_jacobian(y_est);
+        let (l, l_grad) = self.loss.lf_loss_and_grad(y_true, y_link);
+
+        (l, chain(l_grad, jacobian))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, combinator::{IdentityLink, LinkCombinators, SigmoidLink, SoftmaxLink}, BinaryCrossEntropyLoss, BinaryCrossEntropyWithLogits, FocalLoss, FocalLossWithLogits, LossFunction, MeanSquareError};
+
+    fn assert_eq_approx<const Y_EST: usize, const L: usize>((l_a, l_grad_a): ([f64; L], [[f64; Y_EST]; L]), (l_b, l_grad_b): ([f64; L], [[f64; Y_EST]; L]))
+    {
+        assert!(l_a.into_iter().zip(l_b).all(|(a, b)| (a - b).abs() < 1e-12), "Loss mismatch: {:?} != {:?}", l_a, l_b);
+        assert!(
+            l_grad_a.into_iter().flatten().zip(l_grad_b.into_iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-12),
+            "Loss gradient mismatch: {:?} != {:?}", l_grad_a, l_grad_b
+        );
+    }
+
+    #[test]
+    fn test()
+    {
+        let y_true = [1.0, 0.0, 1.0, 0.0, 0.3];
+        let y_est = [2.2, -2.2, 0.5, 0.1, -1.0];
+
+        let lf = SigmoidLink.then(BinaryCrossEntropyLoss.with_epsilon(0.0));
+        t::test(lf, y_true, y_est);
+        t::test_gradient(lf, y_true, y_est);
+        assert_eq_approx(lf.lf_loss_and_grad(y_true, y_est), BinaryCrossEntropyWithLogits.lf_loss_and_grad(y_true, y_est));
+
+        assert_eq_approx(IdentityLink.then(MeanSquareError).lf_loss_and_grad(y_true, y_est), MeanSquareError.lf_loss_and_grad(y_true, y_est));
+
+        let y_true = [0.1, 0.6, 0.1, 0.1, 0.1];
+        let y_est = [1.0, 3.0, -2.0, 0.5, 0.0];
+
+        let lf = SoftmaxLink.then(FocalLoss::new(2.0, 1.0));
+        t::test(lf, y_true, y_est);
+        t::test_gradient(lf, y_true, y_est);
+        assert_eq_approx(lf.lf_loss_and_grad(y_true, y_est), FocalLossWithLogits::new(2.0, 1.0).lf_loss_and_grad(y_true, y_est));
+    }
+}
@@ -0,0 +1,33 @@
+use super::*;
+
+/// Methods for building combined loss functions, such as `MeanSquareError.scaled(0.7).plus(MeanAbsoluteError.scaled(0.3))`.
+/// 
+/// Losses on a [link function](LinkFunction) are built with [LinkCombinators](LinkCombinators) instead.
+pub trait LossCombinators: Sized
+{
+    /// Adds the loss of `other` to this loss.
+    fn plus<T>(self, other: T) -> LossSum<Self, T>
+    {
+        LossSum(self, other)
+    }
+
+    /// Multiplies the loss by `scale`.
+    fn scaled(self, scale: f64) -> ScaledLoss<Self>
+    {
+        ScaledLoss::new(self, scale)
+    }
+
+    /// Multiplies each of the `L` losses by its own weight.
+    fn output_weighted<const L: usize>(self, weights: [f64; L]) -> OutputWeightedLoss<Self, L>
+    {
+        OutputWeightedLoss::new(self, weights)
+    }
+}
+
+/// Implemented for the losses with [LossProperties], so that the combined losses can report theirs too.
+impl<T> LossCombinators for T
+where
+    T: LossProperties
+{
+
+}
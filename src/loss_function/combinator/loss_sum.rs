@@ -0,0 +1,150 @@
+use num::Float;
+
+use super::*;
+
+/// The sum of two loss functions with the same shape.
+#[derive(Clone, Copy, Debug)]
+pub struct LossSum<A, B>(pub A, pub B);
+
+impl<A, B, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunction<F, Y_TRUE, Y_EST, L> for LossSum<A, B>
+where
+    A: LossFunction<F, Y_TRUE, Y_EST, L>,
+    B: LossFunction<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [F; L]
+    {
+        self.0.lf_loss(y_true, y_est)
+            .comap(self.1.lf_loss(y_true, y_est), |l_a, l_b| l_a + l_b)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        self.0.lf_loss_grad(y_true, y_est)
+            .comap(self.1.lf_loss_grad(y_true, y_est), |l_grad_a, l_grad_b| l_grad_a.comap(l_grad_b, |a, b| a + b))
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> ([F; L], [[F; Y_EST]; L])
+    {
+        let (l_a, l_grad_a) = self.0.lf_loss_and_grad(y_true, y_est);
+        let (l_b, l_grad_b) = self.1.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l_a.comap(l_b, |l_a, l_b| l_a + l_b),
+            l_grad_a.comap(l_grad_b, |l_grad_a, l_grad_b| l_grad_a.comap(l_grad_b, |a, b| a + b))
+        )
+    }
+}
+
+impl<A, B, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunctionHessian<F, Y_TRUE, Y_EST, L> for LossSum<A, B>
+where
+    A: LossFunctionHessian<F, Y_TRUE, Y_EST, L>,
+    B: LossFunctionHessian<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[[F; Y_EST]; Y_EST]; L]
+    {
+        self.0.lf_loss_hessian(y_true, y_est)
+            .comap(self.1.lf_loss_hessian(y_true, y_est), |l_hess_a, l_hess_b| l_hess_a.comap(l_hess_b, |a, b| a.comap(b, |a, b| a + b)))
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        self.0.lf_loss_hessian_diagonal(y_true, y_est)
+            .comap(self.1.lf_loss_hessian_diagonal(y_true, y_est), |l_hess_a, l_hess_b| l_hess_a.comap(l_hess_b, |a, b| a + b))
+    }
+}
+
+impl<A, B> LossProperties for LossSum<A, B>
+where
+    A: LossProperties,
+    B: LossProperties
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.0.lf_is_convex() && self.1.lf_is_convex()
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.0.lf_is_differentiable() && self.1.lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.0.lf_lipschitz()? + self.1.lf_lipschitz()?)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(self.0.lf_smoothness()? + self.1.lf_smoothness()?)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        Some(self.0.lf_bound()? + self.1.lf_bound()?)
+    }
+}
+
+impl<A, B, F> DynLossFunction<F> for LossSum<A, B>
+where
+    A: DynLossFunction<F>,
+    B: DynLossFunction<F>,
+    F: Float
+{
+    fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+    {
+        self.0.lf_dyn_y_true_len(y_est_len)
+    }
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        self.0.lf_dyn_loss_len(y_est_len)
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let mut loss_b = vec![F::zero(); loss.len()];
+        self.0.lf_dyn_loss(y_true, y_est, loss)?;
+        self.1.lf_dyn_loss(y_true, y_est, &mut loss_b)?;
+
+        for (loss, loss_b) in loss.iter_mut().zip(loss_b)
+        {
+            *loss = *loss + loss_b;
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let mut loss_grad_b = vec![F::zero(); loss_grad.len()];
+        self.0.lf_dyn_loss_grad(y_true, y_est, loss_grad)?;
+        self.1.lf_dyn_loss_grad(y_true, y_est, &mut loss_grad_b)?;
+
+        for (loss_grad, loss_grad_b) in loss_grad.iter_mut().zip(loss_grad_b)
+        {
+            *loss_grad = *loss_grad + loss_grad_b;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, combinator::LossCombinators, LossFunction, MeanAbsoluteError, MeanSquareError, SquareErrorLoss, HuberLoss};
+
+    #[test]
+    fn test()
+    {
+        let y_true = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_est = [1.2, 2.5, 4.0, 5.0, 4.0];
+
+        let lf = MeanSquareError.scaled(0.7).plus(MeanAbsoluteError.scaled(0.3));
+
+        t::test(lf, y_true, y_est);
+        t::test_dyn(lf, y_true, y_est);
+        t::test_gradient(lf, y_true, y_est);
+
+        let l = lf.lf_loss(y_true, y_est);
+        let l_mse = MeanSquareError.lf_loss(y_true, y_est);
+        let l_mae = MeanAbsoluteError.lf_loss(y_true, y_est);
+        assert!((l[0] - (0.7*l_mse[0] + 0.3*l_mae[0])).abs() < 1e-12);
+
+        t::test_hessian(SquareErrorLoss.plus(HuberLoss::new(0.5)), y_true, y_est);
+    }
+}
@@ -0,0 +1,16 @@
+moddef::moddef!(
+    flat(pub) mod {
+        loss_combinators,
+        loss_sum,
+        scaled_loss,
+        output_weighted_loss,
+
+        link_function,
+        identity_link,
+        sigmoid_link,
+        softmax_link,
+        linked_loss
+    }
+);
+
+use super::*;
@@ -0,0 +1,173 @@
+use num::Float;
+
+use super::*;
+
+/// A loss function with each of its `L` losses multiplied by its own weight.
+#[derive(Clone, Copy, Debug)]
+pub struct OutputWeightedLoss<T, const L: usize>
+{
+    pub loss: T,
+    pub weights: [f64; L]
+}
+
+impl<T, const L: usize> OutputWeightedLoss<T, L>
+{
+    pub fn new(loss: T, weights: [f64; L]) -> Self
+    {
+        Self {
+            loss,
+            weights
+        }
+    }
+}
+
+impl<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunction<F, Y_TRUE, Y_EST, L> for OutputWeightedLoss<T, L>
+where
+    T: LossFunction<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [F; L]
+    {
+        self.loss.lf_loss(y_true, y_est)
+            .comap(self.weights, |l, w| l*f!(w; F))
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        self.loss.lf_loss_grad(y_true, y_est)
+            .comap(self.weights, |l_grad, w| {
+                let w = f!(w; F);
+                l_grad.map(|l_grad| l_grad*w)
+            })
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> ([F; L], [[F; Y_EST]; L])
+    {
+        let (l, l_grad) = self.loss.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(self.weights, |l, w| l*f!(w; F)),
+            l_grad.comap(self.weights, |l_grad, w| {
+                let w = f!(w; F);
+                l_grad.map(|l_grad| l_grad*w)
+            })
+        )
+    }
+}
+
+impl<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunctionHessian<F, Y_TRUE, Y_EST, L> for OutputWeightedLoss<T, L>
+where
+    T: LossFunctionHessian<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[[F; Y_EST]; Y_EST]; L]
+    {
+        self.loss.lf_loss_hessian(y_true, y_est)
+            .comap(self.weights, |l_hess, w| {
+                let w = f!(w; F);
+                l_hess.map(|l_hess| l_hess.map(|l_hess| l_hess*w))
+            })
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        self.loss.lf_loss_hessian_diagonal(y_true, y_est)
+            .comap(self.weights, |l_hess, w| {
+                let w = f!(w; F);
+                l_hess.map(|l_hess| l_hess*w)
+            })
+    }
+}
+
+impl<T, const L: usize> LossProperties for OutputWeightedLoss<T, L>
+where
+    T: LossProperties
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.weights.into_iter().all(|w| w >= 0.0) && self.loss.lf_is_convex()
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.loss.lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.weights.into_iter().map(f64::abs).fold(0.0, f64::max)*self.loss.lf_lipschitz()?)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(self.weights.into_iter().map(f64::abs).fold(0.0, f64::max)*self.loss.lf_smoothness()?)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        if self.weights.into_iter().all(|w| w >= 0.0)
+        {
+            Some(self.weights.into_iter().fold(0.0, f64::max)*self.loss.lf_bound()?)
+        }
+        else
+        {
+            None
+        }
+    }
+}
+
+impl<T, F, const L: usize> DynLossFunction<F> for OutputWeightedLoss<T, L>
+where
+    T: DynLossFunction<F>,
+    F: Float
+{
+    fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+    {
+        self.loss.lf_dyn_y_true_len(y_est_len)
+    }
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        self.loss.lf_dyn_loss_len(y_est_len)
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+        LossFunctionError::check_loss_len(L, loss.len())?;
+
+        self.loss.lf_dyn_loss(y_true, y_est, loss)?;
+
+        for (loss, &w) in loss.iter_mut().zip(self.weights.iter())
+        {
+            *loss = *loss*f!(w; F);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+        LossFunctionError::check_loss_grad_len(L*y_est.len(), loss_grad.len())?;
+
+        self.loss.lf_dyn_loss_grad(y_true, y_est, loss_grad)?;
+
+        for (loss_grad, &w) in loss_grad.chunks_exact_mut(y_est.len()).zip(self.weights.iter())
+        {
+            let w = f!(w; F);
+            for loss_grad in loss_grad.iter_mut()
+            {
+                *loss_grad = *loss_grad*w;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, SquareErrorLoss};
+    use super::OutputWeightedLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let weights = [1.0, 0.5, 2.0, 0.0, 1.5];
+
+        t::test(LF::new(SquareErrorLoss, weights), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(SquareErrorLoss, weights), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(SquareErrorLoss, weights), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_hessian(LF::new(SquareErrorLoss, weights), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+    }
+}
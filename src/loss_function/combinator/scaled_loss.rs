@@ -0,0 +1,163 @@
+use num::Float;
+
+use super::*;
+
+/// A loss function multiplied by a constant.
+#[derive(Clone, Copy, Debug)]
+pub struct ScaledLoss<T>
+{
+    pub loss: T,
+    pub scale: f64
+}
+
+impl<T> ScaledLoss<T>
+{
+    pub fn new(loss: T, scale: f64) -> Self
+    {
+        Self {
+            loss,
+            scale
+        }
+    }
+}
+
+impl<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunction<F, Y_TRUE, Y_EST, L> for ScaledLoss<T>
+where
+    T: LossFunction<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [F; L]
+    {
+        let scale = f!(self.scale; F);
+
+        self.loss.lf_loss(y_true, y_est)
+            .map(|l| l*scale)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        let scale = f!(self.scale; F);
+
+        self.loss.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.map(|l_grad| l_grad*scale))
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> ([F; L], [[F; Y_EST]; L])
+    {
+        let scale = f!(self.scale; F);
+
+        let (l, l_grad) = self.loss.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.map(|l| l*scale),
+            l_grad.map(|l_grad| l_grad.map(|l_grad| l_grad*scale))
+        )
+    }
+}
+
+impl<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize> LossFunctionHessian<F, Y_TRUE, Y_EST, L> for ScaledLoss<T>
+where
+    T: LossFunctionHessian<F, Y_TRUE, Y_EST, L>,
+    F: Float
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[[F; Y_EST]; Y_EST]; L]
+    {
+        let scale = f!(self.scale; F);
+
+        self.loss.lf_loss_hessian(y_true, y_est)
+            .map(|l_hess| l_hess.map(|l_hess| l_hess.map(|l_hess| l_hess*scale)))
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    {
+        let scale = f!(self.scale; F);
+
+        self.loss.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.map(|l_hess| l_hess*scale))
+    }
+}
+
+impl<T> LossProperties for ScaledLoss<T>
+where
+    T: LossProperties
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.scale == 0.0 || (self.scale > 0.0 && self.loss.lf_is_convex())
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.loss.lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.scale.abs()*self.loss.lf_lipschitz()?)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(self.scale.abs()*self.loss.lf_smoothness()?)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        if self.scale >= 0.0
+        {
+            Some(self.scale*self.loss.lf_bound()?)
+        }
+        else
+        {
+            None
+        }
+    }
+}
+
+impl<T, F> DynLossFunction<F> for ScaledLoss<T>
+where
+    T: DynLossFunction<F>,
+    F: Float
+{
+    fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+    {
+        self.loss.lf_dyn_y_true_len(y_est_len)
+    }
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        self.loss.lf_dyn_loss_len(y_est_len)
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.loss.lf_dyn_loss(y_true, y_est, loss)?;
+
+        let scale = f!(self.scale; F);
+
+        for loss in loss.iter_mut()
+        {
+            *loss = *loss*scale;
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.loss.lf_dyn_loss_grad(y_true, y_est, loss_grad)?;
+
+        let scale = f!(self.scale; F);
+
+        for loss_grad in loss_grad.iter_mut()
+        {
+            *loss_grad = *loss_grad*scale;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, SquareErrorLoss};
+    use super::ScaledLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        t::test(LF::new(SquareErrorLoss, 0.25), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(SquareErrorLoss, 0.25), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(SquareErrorLoss, 0.25), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_hessian(LF::new(SquareErrorLoss, 0.25), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+    }
+}
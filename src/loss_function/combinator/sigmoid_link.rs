@@ -0,0 +1,35 @@
+use num::Float;
+
+use super::*;
+
+/// The logistic sigmoid of each score, mapping it onto a probability.
+#[derive(Clone, Copy, Debug)]
+pub struct SigmoidLink;
+
+impl LinkCombinators for SigmoidLink
+{
+
+}
+
+impl<F, const X: usize> LinkFunction<F, X> for SigmoidLink
+where
+    F: Float
+{
+    fn link(&self, x: [F; X]) -> [F; X]
+    {
+        x.map(sigmoid)
+    }
+    fn link_jacobian(&self, x: [F; X]) -> [[F; X]; X]
+    {
+        self.link_and_jacobian(x).1
+    }
+    fn link_and_jacobian(&self, x: [F; X]) -> ([F; X], [[F; X]; X])
+    {
+        let y = x.map(sigmoid);
+
+        (
+            y,
+            <[[F; X]; X]>::fill(|i| <[F; X]>::fill(|j| if i == j {y[i]*(F::one() - y[i])} else {F::zero()}))
+        )
+    }
+}
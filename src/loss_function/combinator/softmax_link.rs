@@ -0,0 +1,39 @@
+use num::Float;
+
+use super::*;
+
+/// The softmax of the logits, mapping them onto a probability distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct SoftmaxLink;
+
+impl LinkCombinators for SoftmaxLink
+{
+
+}
+
+impl<F, const X: usize> LinkFunction<F, X> for SoftmaxLink
+where
+    F: Float
+{
+    fn link(&self, x: [F; X]) -> [F; X]
+    {
+        let max = x.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = x.map(|x| (x - max).exp());
+        let exp_sum_inv = exp.into_iter().fold(F::zero(), |a, b| a + b).recip();
+
+        exp.map(|exp| exp*exp_sum_inv)
+    }
+    fn link_jacobian(&self, x: [F; X]) -> [[F; X]; X]
+    {
+        self.link_and_jacobian(x).1
+    }
+    fn link_and_jacobian(&self, x: [F; X]) -> ([F; X], [[F; X]; X])
+    {
+        let y = self.link(x);
+
+        (
+            y,
+            <[[F; X]; X]>::fill(|i| <[F; X]>::fill(|j| if i == j {y[i]*(F::one() - y[i])} else {-y[i]*y[j]}))
+        )
+    }
+}
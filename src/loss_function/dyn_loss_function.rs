@@ -0,0 +1,27 @@
+use super::*;
+
+/// A loss function operating on slices, for when the length of the estimate is only known at runtime.
+/// 
+/// The loss gradient is written row-major, so that `loss_grad[i*y_est.len() + j]` is the derivative of loss `i` with respect to `y_est[j]`.
+pub trait DynLossFunction<F>
+{
+    fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize;
+
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>;
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>;
+
+    fn lf_dyn_check_loss(&self, y_true: &[F], y_est: &[F], loss: &[F]) -> Result<(), LossFunctionError>
+    {
+        LossFunctionError::check_y_true_len(self.lf_dyn_y_true_len(y_est.len()), y_true.len())?;
+        LossFunctionError::check_loss_len(self.lf_dyn_loss_len(y_est.len()), loss.len())
+    }
+    fn lf_dyn_check_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &[F]) -> Result<(), LossFunctionError>
+    {
+        LossFunctionError::check_y_true_len(self.lf_dyn_y_true_len(y_est.len()), y_true.len())?;
+        LossFunctionError::check_loss_grad_len(self.lf_dyn_loss_len(y_est.len())*y_est.len(), loss_grad.len())
+    }
+}
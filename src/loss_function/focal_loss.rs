@@ -0,0 +1,189 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Focal loss between the target distribution `y_true` and the class probabilities `y_est`, which down-weights each class by `(1 - p)^gamma`.
+/// 
+/// With `gamma = 0`, this is the cross-entropy of the probabilities scaled by `alpha`.
+/// The estimates are clamped like those of [BinaryCrossEntropyLoss](BinaryCrossEntropyLoss), so that estimates of exactly zero or one give a finite loss and gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct FocalLoss
+{
+    pub gamma: f64,
+    pub alpha: f64
+}
+
+impl FocalLoss
+{
+    pub fn new(gamma: f64, alpha: f64) -> Self
+    {
+        Self {
+            gamma,
+            alpha
+        }
+    }
+
+    fn focal<F>(&self, y_true: F, y_est: F) -> F
+    where
+        F: Float
+    {
+        let gamma = f!(self.gamma; F);
+        let alpha = f!(self.alpha; F);
+
+        let y_est = clamp_probability(y_est, BinaryCrossEntropyLoss::DEFAULT_EPSILON);
+
+        -alpha*y_true*(F::one() - y_est).powf(gamma)*y_est.ln()
+    }
+
+    fn focal_and_grad<F>(&self, y_true: F, y_est: F) -> (F, F)
+    where
+        F: Float
+    {
+        let gamma = f!(self.gamma; F);
+        let alpha = f!(self.alpha; F);
+
+        let y_est = clamp_probability(y_est, BinaryCrossEntropyLoss::DEFAULT_EPSILON);
+        let y_est_neg = F::one() - y_est;
+
+        let ln = y_est.ln();
+        let pow = y_est_neg.powf(gamma);
+        let scale = -alpha*y_true;
+
+        (
+            scale*pow*ln,
+            scale*(pow/y_est - gamma*pow/y_est_neg*ln)
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for FocalLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        [
+            y_true.comap(y_est, |y_true, y_est| self.focal(y_true, y_est))
+                .sum()
+        ]
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        [
+            y_true.comap(y_est, |y_true, y_est| self.focal_and_grad(y_true, y_est).1)
+        ]
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| self.focal_and_grad(y_true, y_est));
+
+        (
+            [l_and_grad.map(|(l, _)| l).sum()],
+            [l_and_grad.map(|(_, l_grad)| l_grad)]
+        )
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for FocalLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        self.lf_loss(weight_targets(y_true, weights), y_est)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(weight_targets(y_true, weights), y_est)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        self.lf_loss_and_grad(weight_targets(y_true, weights), y_est)
+    }
+}
+
+impl<F> DynLossFunction<F> for FocalLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        loss[0] = y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.focal(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss_grad = self.focal_and_grad(y_true, y_est).1;
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for FocalLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, CategoricalCrossEntropyLoss, LossFunction};
+    use super::FocalLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let gamma = 2.0;
+        let alpha = 1.0;
+
+        t::test(LF::new(gamma, alpha), [0.0, 1.0, 0.0, 0.0, 0.0], [0.1, 0.6, 0.05, 0.15, 0.1]);
+        t::test_dyn(LF::new(gamma, alpha), [0.0, 1.0, 0.0, 0.0, 0.0], [0.1, 0.6, 0.05, 0.15, 0.1]);
+        t::test_gradient(LF::new(gamma, alpha), [0.0, 1.0, 0.0, 0.0, 0.0], [0.1, 0.6, 0.05, 0.15, 0.1]);
+        t::graph_2d(LF::new(gamma, alpha), [0.2, 0.8], 0.001..0.999);
+
+        let y_true = [0.1, 0.6, 0.1, 0.1, 0.1];
+        let y_est = [0.1, 0.6, 0.05, 0.15, 0.1];
+        let l = LF::new(0.0, 1.0).lf_loss(y_true, y_est);
+        let l_ce = CategoricalCrossEntropyLoss.lf_loss(y_true, y_est.map(f64::ln));
+        assert!((l[0] - l_ce[0]).abs() < 1e-12);
+
+        // Clamped like the binary cross-entropy, so that certain estimates stay finite
+        let (l, l_grad) = LF::new(gamma, alpha).lf_loss_and_grad([0.0, 1.0, 0.0], [1.0, 0.0, 0.0]);
+        assert!(l[0].is_finite() && l_grad[0].into_iter().all(f64::is_finite));
+    }
+}
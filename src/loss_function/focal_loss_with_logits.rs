@@ -0,0 +1,191 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Focal loss between the target distribution `y_true` and the softmax of the logits `y_est`.
+/// 
+/// Equivalent to [FocalLoss](FocalLoss) of `softmax(y_est)`, but with the log-probabilities computed using log-sum-exp, so large logits don't overflow.
+/// With `gamma = 0` and `alpha = 1`, this is the [CategoricalCrossEntropyLoss](CategoricalCrossEntropyLoss).
+#[derive(Clone, Copy, Debug)]
+pub struct FocalLossWithLogits
+{
+    pub gamma: f64,
+    pub alpha: f64
+}
+
+impl FocalLossWithLogits
+{
+    pub fn new(gamma: f64, alpha: f64) -> Self
+    {
+        Self {
+            gamma,
+            alpha
+        }
+    }
+
+    /// Returns the loss term, its gradient with respect to `ln(p)`, and the probability `p`.
+    fn focal_terms<F>(&self, y_true: F, ln_p: F) -> (F, F, F)
+    where
+        F: Float
+    {
+        let one = F::one();
+        let gamma = f!(self.gamma; F);
+        let alpha = f!(self.alpha; F);
+
+        let p = ln_p.exp();
+        let p_neg = one - p;
+        let weight = alpha*y_true*p_neg.powf(gamma);
+
+        (
+            -weight*ln_p,
+            -weight + alpha*y_true*gamma*p*p_neg.powf(gamma - one)*ln_p,
+            p
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for FocalLossWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        self.lf_loss_and_grad(y_true, y_est).0
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_and_grad(y_true, y_est).1
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.map(|y_est| (y_est - max).exp()).sum().ln();
+
+        let terms = y_true.comap(y_est, |y_true, y_est| self.focal_terms(y_true, y_est - lse));
+        let grad_sum = terms.map(|(_, grad, _)| grad).sum();
+
+        (
+            [terms.map(|(l, _, _)| l).sum()],
+            [terms.map(|(_, grad, p)| grad - p*grad_sum)]
+        )
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for FocalLossWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        self.lf_loss(weight_targets(y_true, weights), y_est)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(weight_targets(y_true, weights), y_est)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        self.lf_loss_and_grad(weight_targets(y_true, weights), y_est)
+    }
+}
+
+impl<F> DynLossFunction<F> for FocalLossWithLogits
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let max = y_est.iter().copied().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.iter().fold(F::zero(), |sum, &y_est| sum + (y_est - max).exp()).ln();
+
+        loss[0] = y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.focal_terms(y_true, y_est - lse).0)
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let max = y_est.iter().copied().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.iter().fold(F::zero(), |sum, &y_est| sum + (y_est - max).exp()).ln();
+
+        let grad_sum = y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.focal_terms(y_true, y_est - lse).1)
+            .fold(F::zero(), |a, b| a + b);
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            let (_, grad, p) = self.focal_terms(y_true, y_est - lse);
+            *loss_grad = grad - p*grad_sum;
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for FocalLossWithLogits
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.gamma == 0.0
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        (self.gamma == 0.0).then(|| self.alpha)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        (self.gamma == 0.0).then(|| self.alpha*0.25)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, CategoricalCrossEntropyLoss, FocalLoss, LossFunction};
+    use super::FocalLossWithLogits as LF;
+
+    #[test]
+    fn test()
+    {
+        let gamma = 2.0;
+        let alpha = 1.0;
+
+        t::test(LF::new(gamma, alpha), [0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_dyn(LF::new(gamma, alpha), [0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_gradient(LF::new(gamma, alpha), [0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::graph_2d(LF::new(gamma, alpha), [0.2, 0.8], -1.0..1.0);
+
+        let y_true = [0.1, 0.6, 0.1, 0.1, 0.1];
+        let y_est = [1.0, 3.0, -2.0, 0.5, 0.0];
+
+        let l = LF::new(gamma, alpha).lf_loss(y_true, y_est);
+        let exp_sum: f64 = y_est.map(f64::exp).into_iter().sum();
+        let l_prob = FocalLoss::new(gamma, alpha).lf_loss(y_true, y_est.map(|y_est| y_est.exp()/exp_sum));
+        assert!((l[0] - l_prob[0]).abs() < 1e-12);
+
+        let (l, l_grad) = LF::new(0.0, 1.0).lf_loss_and_grad(y_true, y_est);
+        let (l_ce, l_grad_ce) = CategoricalCrossEntropyLoss.lf_loss_and_grad(y_true, y_est);
+        assert!((l[0] - l_ce[0]).abs() < 1e-12);
+        assert!(l_grad[0].into_iter().zip(l_grad_ce[0]).all(|(a, b)| (a - b).abs() < 1e-12));
+    }
+}
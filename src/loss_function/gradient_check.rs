@@ -0,0 +1,31 @@
+use num::Float;
+
+use super::*;
+
+/// Compares the analytic gradient of a loss function with central finite differences of its loss, using the step size `h`.
+/// 
+/// Returns the error of each component of the gradient, relative to the larger magnitude of the two estimates, or the absolute error if both are smaller than one.
+pub fn gradient_check<T, F, const Y_TRUE: usize, const Y_EST: usize, const L: usize>(lf: &T, y_true: [F; Y_TRUE], y_est: [F; Y_EST], h: F) -> [[F; Y_EST]; L]
+where
+    T: LossFunction<F, Y_TRUE, Y_EST, L> + ?Sized,
+    F: Float
+{
+    let l_grad = lf.lf_loss_grad(y_true, y_est);
+    let two_h_inv = (h + h).recip();
+
+    let l_grad_num = <[[F; L]; Y_EST]>::fill(|k| {
+        let mut y_est_pos = y_est;
+        let mut y_est_neg = y_est;
+        y_est_pos[k] = y_est_pos[k] + h;
+        y_est_neg[k] = y_est_neg[k] - h;
+
+        lf.lf_loss(y_true, y_est_pos)
+            .comap(lf.lf_loss(y_true, y_est_neg), |l_pos, l_neg| (l_pos - l_neg)*two_h_inv)
+    });
+
+    <[[F; Y_EST]; L]>::fill(|i| <[F; Y_EST]>::fill(|k| {
+        let a = l_grad[i][k];
+        let n = l_grad_num[k][i];
+        (a - n).abs()/F::one().max(a.abs().max(n.abs()))
+    }))
+}
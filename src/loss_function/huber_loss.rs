@@ -0,0 +1,196 @@
+use num::Float;
+use super::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct HuberLoss
+{
+    pub delta: f64
+}
+
+impl HuberLoss
+{
+    pub fn new(delta: f64) -> Self
+    {
+        Self {
+            delta
+        }
+    }
+}
+
+impl<F, const N: usize> LossFunction<F, N, N, N> for HuberLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss(&self, y_true: [F; N], y_est: [F; N]) -> [F; N]
+    {
+        let delta = f!(self.delta; F);
+
+        y_true.comap(y_est, |y_true, y_est| {
+            let e = y_est - y_true;
+            if e.abs() <= delta
+            {
+                e*e*f!(0.5)
+            }
+            else
+            {
+                delta*(e.abs() - delta*f!(0.5))
+            }
+        })
+    }
+    fn lf_loss_grad(&self, y_true: [F; N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        let delta = f!(self.delta; F);
+
+        y_true.comap(y_est, |y_true, y_est| (y_est - y_true).max(-delta).min(delta))
+            .diagonal()
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let delta = f!(self.delta; F);
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| {
+            let e = y_est - y_true;
+            if e.abs() <= delta
+            {
+                (e*e*f!(0.5), e)
+            }
+            else
+            {
+                (delta*(e.abs() - delta*f!(0.5)), delta.copysign(e))
+            }
+        });
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const N: usize> LossFunctionHessian<F, N, N, N> for HuberLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; N], y_est: [F; N]) -> [[[F; N]; N]; N]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        let delta = f!(self.delta; F);
+
+        y_true.comap(y_est, |y_true, y_est| if (y_est - y_true).abs() <= delta {F::one()} else {F::zero()})
+            .diagonal()
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, N, N, N> for HuberLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for HuberLoss
+where
+    F: Float + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let delta = f!(self.delta; F);
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            let e = y_est - y_true;
+            *loss = if e.abs() <= delta
+            {
+                e*e*f!(0.5)
+            }
+            else
+            {
+                delta*(e.abs() - delta*f!(0.5))
+            };
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let delta = f!(self.delta; F);
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+        {
+            loss_grad[i*n + i] = (y_est - y_true).max(-delta).min(delta);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for HuberLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.delta)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+    use super::HuberLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let delta = 0.5;
+
+        t::test(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::test_hessian(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::graph_2d(LF::new(delta), [0.3, -0.1], -1.0..1.0);
+    }
+}
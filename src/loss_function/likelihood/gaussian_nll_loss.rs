@@ -0,0 +1,104 @@
+use std::f64::consts::TAU;
+
+use num::Float;
+
+use super::*;
+
+/// Negative log-likelihood of each target under a normal distribution, for heteroscedastic regression.
+/// 
+/// Each sample has two estimates, `[mean, log_variance]`, so `y_est` has twice the length of `y_true`.
+/// Estimating the log-variance keeps the variance positive without clamping.
+#[derive(Clone, Copy, Debug)]
+pub struct GaussianNllLoss;
+
+impl NegativeLogLikelihood<2> for GaussianNllLoss
+{
+    fn nll<F>(&self, y_true: F, [mean, log_variance]: [F; 2]) -> F
+    where
+        F: Float
+    {
+        let e = y_true - mean;
+
+        (f!(TAU.ln(); F) + log_variance + e*e*(-log_variance).exp())*f!(0.5)
+    }
+    fn nll_grad<F>(&self, y_true: F, [mean, log_variance]: [F; 2]) -> [F; 2]
+    where
+        F: Float
+    {
+        let e = y_true - mean;
+        let variance_inv = (-log_variance).exp();
+
+        [
+            -e*variance_inv,
+            (F::one() - e*e*variance_inv)*f!(0.5)
+        ]
+    }
+    fn nll_and_grad<F>(&self, y_true: F, [mean, log_variance]: [F; 2]) -> (F, [F; 2])
+    where
+        F: Float
+    {
+        let e = y_true - mean;
+        let variance_inv = (-log_variance).exp();
+        let r = e*e*variance_inv;
+
+        (
+            (f!(TAU.ln(); F) + log_variance + r)*f!(0.5),
+            [
+                -e*variance_inv,
+                (F::one() - r)*f!(0.5)
+            ]
+        )
+    }
+}
+
+impl_nll_loss_function!(GaussianNllLoss, 2);
+
+impl LossProperties for GaussianNllLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use statrs::distribution::{Continuous, Normal};
+
+    use crate::{tests as t, LossFunction};
+    use super::GaussianNllLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_est = [1.0, 0.0, 2.0, -0.5, 4.0, 0.5, 5.0, 0.2, 4.0, -0.3];
+
+        t::test(LF, y_true, y_est);
+        t::test_dyn(LF, y_true, y_est);
+        t::test_gradient(LF, y_true, y_est);
+
+        for (i, l) in LF.lf_loss(y_true, y_est).into_iter().enumerate()
+        {
+            let l_statrs = -Normal::new(y_est[2*i], (0.5*y_est[2*i + 1]).exp()).unwrap().ln_pdf(y_true[i]);
+            assert!((l - l_statrs).abs() < 1e-12, "Loss mismatch at {}: {} != {}", i, l, l_statrs);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use std::f64::consts::LN_2;
+
+use num::Float;
+
+use super::*;
+
+/// Negative log-likelihood of each target under a Laplace distribution, a robust alternative to [`GaussianNllLoss`].
+/// 
+/// Each sample has two estimates, `[location, log_scale]`, so `y_est` has twice the length of `y_true`.
+/// At the kink, where the location equals the target, the gradient is the one from the right. Use [with_subgradient](LaplaceNllLoss::with_subgradient) to pick another.
+#[derive(Clone, Copy, Debug)]
+pub struct LaplaceNllLoss;
+
+impl LaplaceNllLoss
+{
+    pub fn with_subgradient(self, subgradient: Subgradient) -> WithSubgradient<Self>
+    {
+        WithSubgradient::new(self, subgradient)
+    }
+}
+
+impl NegativeLogLikelihood<2> for LaplaceNllLoss
+{
+    fn nll<F>(&self, y_true: F, params: [F; 2]) -> F
+    where
+        F: Float
+    {
+        self.with_subgradient(Subgradient::Right).nll(y_true, params)
+    }
+    fn nll_grad<F>(&self, y_true: F, params: [F; 2]) -> [F; 2]
+    where
+        F: Float
+    {
+        self.with_subgradient(Subgradient::Right).nll_grad(y_true, params)
+    }
+}
+
+impl NegativeLogLikelihood<2> for WithSubgradient<LaplaceNllLoss>
+{
+    fn nll<F>(&self, y_true: F, [location, log_scale]: [F; 2]) -> F
+    where
+        F: Float
+    {
+        f!(LN_2; F) + log_scale + (location - y_true).abs()*(-log_scale).exp()
+    }
+    fn nll_grad<F>(&self, y_true: F, [location, log_scale]: [F; 2]) -> [F; 2]
+    where
+        F: Float
+    {
+        let one = F::one();
+
+        let e = location - y_true;
+        let scale_inv = (-log_scale).exp();
+
+        [
+            self.subgradient.pick(
+                if e > F::zero() {one} else {-one},
+                if e < F::zero() {-one} else {one}
+            )*scale_inv,
+            one - e.abs()*scale_inv
+        ]
+    }
+}
+
+impl_nll_loss_function!(LaplaceNllLoss, 2);
+impl_nll_loss_function!(WithSubgradient<LaplaceNllLoss>, 2);
+
+impl LossProperties for LaplaceNllLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use statrs::distribution::{Continuous, Laplace};
+
+    use crate::{tests as t, LossFunction, Subgradient};
+    use super::LaplaceNllLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_est = [1.0, 0.0, 2.0, -0.5, 4.0, 0.5, 5.0, 0.2, 4.0, -0.3];
+        let y_est_smooth = [1.5, 0.0, 2.5, -0.5, 4.0, 0.5, 5.0, 0.2, 4.0, -0.3];
+
+        t::test(LF, y_true, y_est);
+        t::test_dyn(LF, y_true, y_est);
+        t::test_gradient(LF, y_true, y_est_smooth);
+
+        for (i, l) in LF.lf_loss(y_true, y_est).into_iter().enumerate()
+        {
+            let l_statrs = -Laplace::new(y_est[2*i], y_est[2*i + 1].exp()).unwrap().ln_pdf(y_true[i]);
+            assert!((l - l_statrs).abs() < 1e-12, "Loss mismatch at {}: {} != {}", i, l, l_statrs);
+        }
+
+        for (subgradient, l_grad) in [(Subgradient::Left, -1.0), (Subgradient::Right, 1.0), (Subgradient::Zero, 0.0), (Subgradient::Midpoint, 0.0)]
+        {
+            let l_grad_kink = LF.with_subgradient(subgradient).lf_loss_grad(y_true, y_est)[0][0];
+            assert!((l_grad_kink - l_grad).abs() < 1e-12, "Subgradient mismatch for {:?}: {} != {}", subgradient, l_grad_kink, l_grad);
+        }
+        let l_grad_kink = LF.lf_loss_grad(y_true, y_est)[0][0];
+        assert!((l_grad_kink - 1.0).abs() < 1e-12, "Default subgradient mismatch: {} != 1", l_grad_kink);
+    }
+}
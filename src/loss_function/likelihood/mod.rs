@@ -0,0 +1,13 @@
+moddef::moddef!(
+    flat(pub) mod {
+        negative_log_likelihood,
+
+        gaussian_nll_loss,
+        laplace_nll_loss,
+        student_t_nll_loss,
+        poisson_nll_loss,
+        negative_binomial_nll_loss
+    }
+);
+
+use super::*;
@@ -0,0 +1,91 @@
+use num::Float;
+
+use super::*;
+
+/// Negative log-likelihood of each count under a negative binomial distribution, for counts more dispersed than [`PoissonNllLoss`] allows.
+/// 
+/// Each sample has two estimates, `[log_mean, log_dispersion]`, so `y_est` has twice the length of `y_true`.
+/// The variance is `mean + dispersion*mean²`, which tends to the Poisson distribution as the dispersion goes to zero.
+#[derive(Clone, Copy, Debug)]
+pub struct NegativeBinomialNllLoss;
+
+impl NegativeLogLikelihood<2> for NegativeBinomialNllLoss
+{
+    fn nll<F>(&self, y_true: F, [log_mean, log_dispersion]: [F; 2]) -> F
+    where
+        F: Float
+    {
+        let mean = log_mean.exp();
+        let r = (-log_dispersion).exp();
+
+        ln_gamma(r) - ln_gamma(y_true + r) + ln_gamma(y_true + F::one())
+            + r*(mean/r).ln_1p()
+            + y_true*(r/mean).ln_1p()
+    }
+    fn nll_grad<F>(&self, y_true: F, [log_mean, log_dispersion]: [F; 2]) -> [F; 2]
+    where
+        F: Float
+    {
+        let mean = log_mean.exp();
+        let r = (-log_dispersion).exp();
+
+        [
+            mean*(r + y_true)/(r + mean) - y_true,
+            -r*(digamma(r) - digamma(y_true + r) + (mean/r).ln_1p() + (y_true - mean)/(r + mean))
+        ]
+    }
+}
+
+impl_nll_loss_function!(NegativeBinomialNllLoss, 2);
+
+impl LossProperties for NegativeBinomialNllLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use statrs::distribution::{Discrete, NegativeBinomial};
+
+    use crate::{tests as t, LossFunction};
+    use super::NegativeBinomialNllLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [0.0, 2.0, 3.0, 1.0, 7.0];
+        let y_est = [-1.0, 0.0, 0.5, -0.5, 1.0, 0.5, 0.2, -2.0, 2.5, -1.0];
+
+        t::test(LF, y_true, y_est);
+        t::test_dyn(LF, y_true, y_est);
+        t::test_gradient(LF, y_true, y_est);
+
+        for (i, l) in LF.lf_loss(y_true, y_est).into_iter().enumerate()
+        {
+            let mean = y_est[2*i].exp();
+            let r = (-y_est[2*i + 1]).exp();
+            let l_statrs = -NegativeBinomial::new(r, r/(r + mean)).unwrap().ln_pmf(y_true[i] as u64);
+            assert!((l - l_statrs).abs() < 1e-9, "Loss mismatch at {}: {} != {}", i, l, l_statrs);
+        }
+    }
+}
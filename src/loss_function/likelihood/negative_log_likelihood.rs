@@ -0,0 +1,357 @@
+use std::f64::consts::{PI, TAU};
+
+use num::Float;
+
+use super::*;
+
+/// The negative log-likelihood of a target under a distribution with `K` parameters, which are estimated for each sample.
+/// 
+/// Implementing `nll` and its gradient `nll_grad` with respect to the parameters is enough to build the loss of `N` samples,
+/// where the parameters of sample `i` are `y_est[K*i..K*(i + 1)]` and there is one loss per sample.
+pub trait NegativeLogLikelihood<const K: usize>
+{
+    fn nll<F>(&self, y_true: F, params: [F; K]) -> F
+    where
+        F: Float;
+    fn nll_grad<F>(&self, y_true: F, params: [F; K]) -> [F; K]
+    where
+        F: Float;
+
+    /// Computes both `nll` and its gradient, sharing any intermediate terms between them.
+    fn nll_and_grad<F>(&self, y_true: F, params: [F; K]) -> (F, [F; K])
+    where
+        F: Float
+    {
+        (self.nll(y_true, params), self.nll_grad(y_true, params))
+    }
+}
+
+/// Implements [LossFunction](LossFunction), [WeightedLossFunction](WeightedLossFunction) and [DynLossFunction](DynLossFunction)
+/// for a [NegativeLogLikelihood](NegativeLogLikelihood), given the type and its number of parameters per sample.
+macro_rules! impl_nll_loss_function
+{
+    ($lf:ty, $k:literal) => {
+        impl<F, const N: usize> LossFunction<F, N, {$k*N}, N> for $lf
+        where
+            F: Float
+        {
+            fn lf_loss(&self, y_true: [F; N], y_est: [F; $k*N]) -> [F; N]
+            {
+                nll_loss(self, y_true, y_est)
+            }
+            fn lf_loss_grad(&self, y_true: [F; N], y_est: [F; $k*N]) -> [[F; $k*N]; N]
+            {
+                nll_loss_grad(self, y_true, y_est)
+            }
+            fn lf_loss_and_grad(&self, y_true: [F; N], y_est: [F; $k*N]) -> ([F; N], [[F; $k*N]; N])
+            {
+                nll_loss_and_grad(self, y_true, y_est)
+            }
+        }
+
+        /// Each sample is weighted by the weight of its first parameter, so sample `i` by `weights[K*i]`.
+        /// The weights of its other parameters are ignored, since the loss of a sample can not be split between its parameters.
+        impl<F, const N: usize> WeightedLossFunction<F, N, {$k*N}, N> for $lf
+        where
+            F: Float
+        {
+            fn lf_weighted_loss(&self, y_true: [F; N], y_est: [F; $k*N], weights: [F; $k*N]) -> [F; N]
+            {
+                self.lf_loss(y_true, y_est)
+                    .comap(nll_sample_weights::<_, $k, N, {$k*N}>(weights), |l, w| l*w)
+            }
+            fn lf_weighted_loss_grad(&self, y_true: [F; N], y_est: [F; $k*N], weights: [F; $k*N]) -> [[F; $k*N]; N]
+            {
+                self.lf_loss_grad(y_true, y_est)
+                    .comap(nll_sample_weights::<_, $k, N, {$k*N}>(weights), |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+            }
+            fn lf_weighted_loss_and_grad(&self, y_true: [F; N], y_est: [F; $k*N], weights: [F; $k*N]) -> ([F; N], [[F; $k*N]; N])
+            {
+                let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+                let weights = nll_sample_weights::<_, $k, N, {$k*N}>(weights);
+
+                (
+                    l.comap(weights, |l, w| l*w),
+                    l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+                )
+            }
+        }
+
+        impl<F> DynLossFunction<F> for $lf
+        where
+            F: Float
+        {
+            fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+            {
+                y_est_len/$k
+            }
+            fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+            {
+                y_est_len/$k
+            }
+            fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+            {
+                self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+                nll_dyn_loss(self, y_true, y_est, loss)
+            }
+            fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+            {
+                self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+                nll_dyn_loss_grad(self, y_true, y_est, loss_grad)
+            }
+        }
+    };
+}
+pub(crate) use impl_nll_loss_function;
+
+/// Shift of the Lanczos approximation of the gamma function.
+const LANCZOS_G: f64 = 7.0;
+/// Coefficients of the Lanczos approximation for `g = 7`.
+const LANCZOS_COEFFS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7
+];
+
+/// Natural logarithm of the absolute value of the gamma function, by the Lanczos approximation.
+/// 
+/// Only the arithmetic of `F` is used, so the derivatives of [dual numbers](crate::Dual) are carried through.
+/// The likelihoods are generic over `F`, with no separate path for `f64`, so this is used in place of `statrs`,
+/// which only serves as the reference it is tested against.
+pub(crate) fn ln_gamma<F>(x: F) -> F
+where
+    F: Float
+{
+    let half = f!(0.5; F);
+
+    if x < half
+    {
+        // Reflection formula
+        let pi = f!(PI; F);
+        return (pi/(pi*x).sin().abs()).ln() - ln_gamma(F::one() - x)
+    }
+
+    let x = x - F::one();
+    let a = LANCZOS_COEFFS[1..].iter()
+        .enumerate()
+        .fold(f!(LANCZOS_COEFFS[0]; F), |a, (i, &c)| a + f!(c; F)/(x + f!(i + 1; F)));
+    let t = x + f!(LANCZOS_G + 0.5; F);
+
+    f!(TAU.ln()*0.5; F) + (x + half)*t.ln() - t + a.ln()
+}
+
+/// The digamma function, which is the derivative of [ln_gamma](ln_gamma), by recurrence up to its asymptotic series.
+/// 
+/// Like [ln_gamma](ln_gamma), only the arithmetic of `F` is used, so the derivatives of [dual numbers](crate::Dual) are carried through.
+pub(crate) fn digamma<F>(x: F) -> F
+where
+    F: Float
+{
+    if x <= F::zero()
+    {
+        if x == x.floor()
+        {
+            return F::nan()
+        }
+        // Reflection formula
+        let pi = f!(PI; F);
+        return digamma(F::one() - x) - pi/(pi*x).tan()
+    }
+
+    let mut x = x;
+    let mut psi = F::zero();
+    while x < f!(10.0; F)
+    {
+        psi = psi - x.recip();
+        x = x + F::one();
+    }
+
+    let x2_inv = (x*x).recip();
+    psi + x.ln() - f!(0.5; F)/x
+        - x2_inv*(f!(1.0/12.0; F) - x2_inv*(f!(1.0/120.0; F) - x2_inv*(f!(1.0/252.0; F) - x2_inv*(f!(1.0/240.0; F) - x2_inv*f!(1.0/132.0; F)))))
+}
+
+fn nll_params<F, const K: usize>(y_est: &[F], i: usize) -> [F; K]
+where
+    F: Copy
+{
+    <[F; K]>::fill(|k| y_est[K*i + k])
+}
+
+fn nll_grad_row<F, const K: usize, const Y_EST: usize>(i: usize, l_grad: [F; K]) -> [F; Y_EST]
+where
+    F: Float
+{
+    <[F; Y_EST]>::fill(|j| if j/K == i {l_grad[j % K]} else {F::zero()})
+}
+
+pub(crate) fn nll_loss<T, F, const K: usize, const N: usize, const Y_EST: usize>(lf: &T, y_true: [F; N], y_est: [F; Y_EST]) -> [F; N]
+where
+    T: NegativeLogLikelihood<K> + ?Sized,
+    F: Float
+{
+    <[F; N]>::fill(|i| lf.nll(y_true[i], nll_params(&y_est, i)))
+}
+
+pub(crate) fn nll_loss_grad<T, F, const K: usize, const N: usize, const Y_EST: usize>(lf: &T, y_true: [F; N], y_est: [F; Y_EST]) -> [[F; Y_EST]; N]
+where
+    T: NegativeLogLikelihood<K> + ?Sized,
+    F: Float
+{
+    <[[F; Y_EST]; N]>::fill(|i| nll_grad_row(i, lf.nll_grad(y_true[i], nll_params(&y_est, i))))
+}
+
+pub(crate) fn nll_loss_and_grad<T, F, const K: usize, const N: usize, const Y_EST: usize>(lf: &T, y_true: [F; N], y_est: [F; Y_EST]) -> ([F; N], [[F; Y_EST]; N])
+where
+    T: NegativeLogLikelihood<K> + ?Sized,
+    F: Float
+{
+    let l_and_grad = <[(F, [F; K]); N]>::fill(|i| lf.nll_and_grad(y_true[i], nll_params(&y_est, i)));
+
+    (
+        l_and_grad.map(|(l, _)| l),
+        <[[F; Y_EST]; N]>::fill(|i| nll_grad_row(i, l_and_grad[i].1))
+    )
+}
+
+/// The weight of each sample, which is the weight of its first parameter. The weights of the other parameters are ignored.
+pub(crate) fn nll_sample_weights<F, const K: usize, const N: usize, const Y_EST: usize>(weights: [F; Y_EST]) -> [F; N]
+where
+    F: Copy
+{
+    <[F; N]>::fill(|i| weights[K*i])
+}
+
+pub(crate) fn nll_dyn_loss<T, F, const K: usize>(lf: &T, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+where
+    T: NegativeLogLikelihood<K> + ?Sized,
+    F: Float
+{
+    LossFunctionError::check_y_est_len(K*y_true.len(), y_est.len())?;
+
+    for (i, (loss, &y_true)) in loss.iter_mut().zip(y_true).enumerate()
+    {
+        *loss = lf.nll(y_true, nll_params(y_est, i));
+    }
+    Ok(())
+}
+
+pub(crate) fn nll_dyn_loss_grad<T, F, const K: usize>(lf: &T, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+where
+    T: NegativeLogLikelihood<K> + ?Sized,
+    F: Float
+{
+    LossFunctionError::check_y_est_len(K*y_true.len(), y_est.len())?;
+
+    let n = y_est.len();
+    loss_grad.fill(F::zero());
+    for (i, &y_true) in y_true.iter().enumerate()
+    {
+        for (k, l_grad) in lf.nll_grad(y_true, nll_params(y_est, i)).into_iter().enumerate()
+        {
+            loss_grad[i*n + K*i + k] = l_grad;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{autodiff_grad, Dual, LossFunction};
+
+    use super::*;
+
+    /// The negative log-density of an exponential distribution, with the log-rate as its parameter.
+    struct ExponentialNll;
+
+    impl NegativeLogLikelihood<1> for ExponentialNll
+    {
+        fn nll<F>(&self, y_true: F, [log_rate]: [F; 1]) -> F
+        where
+            F: Float
+        {
+            log_rate.exp()*y_true - log_rate
+        }
+        fn nll_grad<F>(&self, y_true: F, [log_rate]: [F; 1]) -> [F; 1]
+        where
+            F: Float
+        {
+            [log_rate.exp()*y_true - F::one()]
+        }
+    }
+
+    #[test]
+    fn test()
+    {
+        let y_true = [1.0, 2.0];
+        let y_est = [0.0, 0.5];
+
+        let l = nll_loss(&ExponentialNll, y_true, y_est);
+        let l_grad = nll_loss_grad(&ExponentialNll, y_true, y_est);
+        assert_eq!(l, [1.0, 2.0*0.5f64.exp() - 0.5]);
+        assert_eq!(l_grad, [[0.0, 0.0], [0.0, 2.0*0.5f64.exp() - 1.0]]);
+        assert_eq!(nll_loss_and_grad(&ExponentialNll, y_true, y_est), (l, l_grad));
+
+        let mut l_dyn = [0.0; 2];
+        let mut l_grad_dyn = [0.0; 4];
+        nll_dyn_loss(&ExponentialNll, &y_true, &y_est, &mut l_dyn).unwrap();
+        nll_dyn_loss_grad(&ExponentialNll, &y_true, &y_est, &mut l_grad_dyn).unwrap();
+        assert_eq!(l_dyn, l);
+        assert_eq!(l_grad_dyn, [0.0, 0.0, 0.0, 2.0*0.5f64.exp() - 1.0]);
+
+        assert_eq!(nll_sample_weights::<_, 2, 2, 4>([1.0, 2.0, 3.0, 4.0]), [1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_gamma()
+    {
+        for x in [1e-3, 0.3, 0.5, 1.0, 2.5, 7.0, 12.5, 150.0]
+        {
+            let l = ln_gamma(x);
+            let l_statrs = statrs::function::gamma::ln_gamma(x);
+            assert!((l - l_statrs).abs() < 1e-10*l_statrs.abs().max(1.0), "ln_gamma mismatch at {}: {} != {}", x, l, l_statrs);
+
+            let psi = digamma(x);
+            let psi_statrs = statrs::function::gamma::digamma(x);
+            assert!((psi - psi_statrs).abs() < 1e-10*psi_statrs.abs().max(1.0), "digamma mismatch at {}: {} != {}", x, psi, psi_statrs);
+
+            // The derivative of ln_gamma is digamma, and of digamma the trigamma function
+            let l_grad = ln_gamma(Dual::variable(x)).du;
+            assert!((l_grad - psi).abs() < 1e-9*psi.abs().max(1.0), "ln_gamma derivative mismatch at {}: {} != {}", x, l_grad, psi);
+            assert!(digamma(Dual::variable(x)).du.is_finite());
+        }
+        assert!(digamma(-2.0f64).is_nan());
+        assert!((digamma(-0.5f64) - digamma(1.5f64)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_autodiff()
+    {
+        fn assert_grad_eq<const Y_EST: usize, const N: usize>(l_grad: [[f64; Y_EST]; N], l_grad_ad: [[f64; Y_EST]; N])
+        {
+            assert!(
+                l_grad.into_iter().flatten().zip(l_grad_ad.into_iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-9),
+                "Autodiff gradient mismatch: {:?} != {:?}", l_grad_ad, l_grad
+            );
+        }
+
+        let y_true = [0.0, 2.0, 3.0];
+        let y_est = [-1.0, 0.5, 1.0];
+        assert_grad_eq(PoissonNllLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&PoissonNllLoss, y_true, y_est));
+
+        let y_est = [-1.0, 0.3, 0.5, -2.0, 1.0, 1.5];
+        assert_grad_eq(NegativeBinomialNllLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&NegativeBinomialNllLoss, y_true, y_est));
+
+        let y_est = [0.5, 0.0, 1.0, 2.5, -0.5, 0.2, 2.0, 0.3, 3.0];
+        assert_grad_eq(StudentTNllLoss.lf_loss_grad(y_true, y_est), autodiff_grad(&StudentTNllLoss, y_true, y_est));
+    }
+}
@@ -0,0 +1,77 @@
+use num::Float;
+
+use super::*;
+
+/// Negative log-likelihood of each count under a Poisson distribution, with one estimate per sample being the log of its rate.
+/// 
+/// The log link keeps the rate positive, and makes the loss convex in the estimate.
+#[derive(Clone, Copy, Debug)]
+pub struct PoissonNllLoss;
+
+impl NegativeLogLikelihood<1> for PoissonNllLoss
+{
+    fn nll<F>(&self, y_true: F, [log_rate]: [F; 1]) -> F
+    where
+        F: Float
+    {
+        log_rate.exp() - y_true*log_rate + ln_gamma(y_true + F::one())
+    }
+    fn nll_grad<F>(&self, y_true: F, [log_rate]: [F; 1]) -> [F; 1]
+    where
+        F: Float
+    {
+        [log_rate.exp() - y_true]
+    }
+}
+
+impl_nll_loss_function!(PoissonNllLoss, 1);
+
+impl LossProperties for PoissonNllLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use statrs::distribution::{Discrete, Poisson};
+
+    use crate::{tests as t, LossFunction};
+    use super::PoissonNllLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [0.0, 2.0, 3.0, 1.0, 7.0];
+        let y_est = [-1.0, 0.5, 1.0, 0.2, 2.5];
+
+        t::test(LF, y_true, y_est);
+        t::test_dyn(LF, y_true, y_est);
+        t::test_gradient(LF, y_true, y_est);
+
+        for (i, l) in LF.lf_loss(y_true, y_est).into_iter().enumerate()
+        {
+            let l_statrs = -Poisson::new(y_est[i].exp()).unwrap().ln_pmf(y_true[i] as u64);
+            assert!((l - l_statrs).abs() < 1e-9, "Loss mismatch at {}: {} != {}", i, l, l_statrs);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::f64::consts::PI;
+
+use num::Float;
+
+use super::*;
+
+/// Negative log-likelihood of each target under a Student-t distribution, whose heavy tails make it robust to outliers.
+/// 
+/// Each sample has three estimates, `[location, log_scale, log_freedom]`, so `y_est` has three times the length of `y_true`.
+/// Estimating the degrees of freedom lets the model learn how heavy the tails of the noise are.
+#[derive(Clone, Copy, Debug)]
+pub struct StudentTNllLoss;
+
+impl NegativeLogLikelihood<3> for StudentTNllLoss
+{
+    fn nll<F>(&self, y_true: F, [location, log_scale, log_freedom]: [F; 3]) -> F
+    where
+        F: Float
+    {
+        let half = f!(0.5; F);
+
+        let freedom = log_freedom.exp();
+        let z = (y_true - location)*(-log_scale).exp();
+
+        ln_gamma(freedom*half) - ln_gamma((freedom + F::one())*half)
+            + (freedom*f!(PI)).ln()*half
+            + log_scale
+            + (freedom + F::one())*half*(z*z/freedom).ln_1p()
+    }
+    fn nll_grad<F>(&self, y_true: F, [location, log_scale, log_freedom]: [F; 3]) -> [F; 3]
+    where
+        F: Float
+    {
+        let one = F::one();
+        let half = f!(0.5; F);
+
+        let freedom = log_freedom.exp();
+        let scale_inv = (-log_scale).exp();
+        let z = (y_true - location)*scale_inv;
+        let w = (freedom + one)*z*z/(freedom + z*z);
+
+        [
+            -(freedom + one)*z*scale_inv/(freedom + z*z),
+            one - w,
+            (freedom*(digamma(freedom*half) - digamma((freedom + one)*half) + (z*z/freedom).ln_1p()) + one - w)*half
+        ]
+    }
+}
+
+impl_nll_loss_function!(StudentTNllLoss, 3);
+
+impl LossProperties for StudentTNllLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use statrs::distribution::{Continuous, StudentsT};
+
+    use crate::{tests as t, LossFunction};
+    use super::StudentTNllLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_est = [1.0, 0.0, 1.0, 2.0, -0.5, 0.0, 4.0, 0.5, 2.0, 5.0, 0.2, -1.0, 4.0, -0.3, 0.5];
+
+        t::test(LF, y_true, y_est);
+        t::test_dyn(LF, y_true, y_est);
+        t::test_gradient(LF, y_true, y_est);
+
+        for (i, l) in LF.lf_loss(y_true, y_est).into_iter().enumerate()
+        {
+            let l_statrs = -StudentsT::new(y_est[3*i], y_est[3*i + 1].exp(), y_est[3*i + 2].exp()).unwrap().ln_pdf(y_true[i]);
+            assert!((l - l_statrs).abs() < 1e-9, "Loss mismatch at {}: {} != {}", i, l, l_statrs);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LossFunctionError
+{
+    YTrueLength {expected: usize, found: usize},
+    YEstLength {expected: usize, found: usize},
+    LossLength {expected: usize, found: usize},
+    LossGradLength {expected: usize, found: usize},
+    BatchLength {expected: usize, found: usize},
+    ClassIndex {classes: usize},
+    EmptyBatch,
+    ZeroWeightSum
+}
+
+impl LossFunctionError
+{
+    pub fn check_y_true_len(expected: usize, found: usize) -> Result<(), Self>
+    {
+        if expected != found
+        {
+            return Err(Self::YTrueLength {expected, found})
+        }
+        Ok(())
+    }
+    pub fn check_y_est_len(expected: usize, found: usize) -> Result<(), Self>
+    {
+        if expected != found
+        {
+            return Err(Self::YEstLength {expected, found})
+        }
+        Ok(())
+    }
+    pub fn check_loss_len(expected: usize, found: usize) -> Result<(), Self>
+    {
+        if expected != found
+        {
+            return Err(Self::LossLength {expected, found})
+        }
+        Ok(())
+    }
+    pub fn check_loss_grad_len(expected: usize, found: usize) -> Result<(), Self>
+    {
+        if expected != found
+        {
+            return Err(Self::LossGradLength {expected, found})
+        }
+        Ok(())
+    }
+    pub fn check_batch_len(expected: usize, found: usize) -> Result<(), Self>
+    {
+        if expected != found
+        {
+            return Err(Self::BatchLength {expected, found})
+        }
+        Ok(())
+    }
+}
+
+impl Display for LossFunctionError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Self::YTrueLength {expected, found} => write!(f, "Expected y_true of length {}, found length {}.", expected, found),
+            Self::YEstLength {expected, found} => write!(f, "Expected y_est of length {}, found length {}.", expected, found),
+            Self::LossLength {expected, found} => write!(f, "Expected loss buffer of length {}, found length {}.", expected, found),
+            Self::LossGradLength {expected, found} => write!(f, "Expected loss gradient buffer of length {}, found length {}.", expected, found),
+            Self::BatchLength {expected, found} => write!(f, "Expected a batch of {} samples, found {} samples.", expected, found),
+            Self::ClassIndex {classes} => write!(f, "Expected y_true to be a class index below {}.", classes),
+            Self::EmptyBatch => write!(f, "Expected a non-empty batch to take the mean of."),
+            Self::ZeroWeightSum => write!(f, "Expected sample weights with a nonzero sum to take the weighted mean.")
+        }
+    }
+}
+
+impl std::error::Error for LossFunctionError
+{
+
+}
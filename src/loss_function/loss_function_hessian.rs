@@ -0,0 +1,18 @@
+use super::*;
+
+/// A loss function with known second derivatives.
+/// 
+/// `lf_loss_hessian(y_true, y_est)[i][j][k]` is the second derivative of loss `i` with respect to `y_est[j]` and `y_est[k]`.
+pub trait LossFunctionHessian<F, const Y_TRUE: usize, const Y_EST: usize, const L: usize = 1>: LossFunction<F, Y_TRUE, Y_EST, L>
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[[F; Y_EST]; Y_EST]; L];
+
+    /// Only the diagonal of the hessian, which is all that is needed for diagonal Newton methods and gradient boosting.
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L]
+    where
+        F: Copy
+    {
+        self.lf_loss_hessian(y_true, y_est)
+            .map(|l_hess| <[F; Y_EST]>::fill(|i| l_hess[i][i]))
+    }
+}
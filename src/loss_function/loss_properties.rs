@@ -0,0 +1,17 @@
+/// Analytic properties of a loss function, for choosing step sizes and optimization algorithms.
+/// 
+/// The constants describe each loss output as a function of a single element of `y_est`, for any valid `y_true` and within the domain the loss is meant for.
+/// Losses which take the mean over their elements are described by the terms of the mean, which can only overestimate the constants of the mean itself.
+pub trait LossProperties
+{
+    /// Whether the loss is convex in `y_est`.
+    fn lf_is_convex(&self) -> bool;
+    /// Whether the loss is differentiable everywhere in `y_est`.
+    fn lf_is_differentiable(&self) -> bool;
+    /// A Lipschitz constant of the loss, or `None` if no finite constant is known.
+    fn lf_lipschitz(&self) -> Option<f64>;
+    /// A Lipschitz constant of the gradient of the loss, making it L-smooth, or `None` if no finite constant is known.
+    fn lf_smoothness(&self) -> Option<f64>;
+    /// An upper bound of the loss, or `None` if it is unbounded.
+    fn lf_bound(&self) -> Option<f64>;
+}
@@ -5,10 +5,94 @@ use num_identities_const::ZeroConst;
 
 use super::*;
 
+/// The mean of the absolute errors.
+/// 
+/// At the kink, where `y_est == y_true`, the gradient is the one from the right. Use [with_subgradient](MeanAbsoluteError::with_subgradient) to pick another.
 #[derive(Clone, Copy, Debug)]
 pub struct MeanAbsoluteError;
 
+fn absolute_error<F>(y_true: F, y_est: F) -> F
+where
+    F: Float
+{
+    (y_est - y_true).abs()
+}
+
+impl MeanAbsoluteError
+{
+    pub fn with_subgradient(self, subgradient: Subgradient) -> WithSubgradient<Self>
+    {
+        WithSubgradient::new(self, subgradient)
+    }
+}
+
+impl WithSubgradient<MeanAbsoluteError>
+{
+    fn abs_grad<F>(&self, e: F) -> F
+    where
+        F: Float
+    {
+        let zero = F::zero();
+        let one = F::one();
+
+        self.subgradient.pick(
+            if e > zero {one} else {-one},
+            if e < zero {-one} else {one}
+        )
+    }
+}
+
 impl<F, const Y: usize> LossFunction<F, Y, Y> for MeanAbsoluteError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        self.with_subgradient(Subgradient::Right).lf_loss(y_true, y_est)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.with_subgradient(Subgradient::Right).lf_loss_grad(y_true, y_est)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        self.with_subgradient(Subgradient::Right).lf_loss_and_grad(y_true, y_est)
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for MeanAbsoluteError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        self.with_subgradient(Subgradient::Right).lf_weighted_loss(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.with_subgradient(Subgradient::Right).lf_weighted_loss_grad(y_true, y_est, weights)
+    }
+}
+
+impl<F> DynLossFunction<F> for MeanAbsoluteError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Right).lf_dyn_loss(y_true, y_est, loss)
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Right).lf_dyn_loss_grad(y_true, y_est, loss_grad)
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for WithSubgradient<MeanAbsoluteError>
 where
     F: Float + AddAssign + ZeroConst
 {
@@ -17,7 +101,7 @@ where
         let len_inv = f!(Y; F).recip();
         
         [
-            len_inv*y_true.comap(y_est, |y_true, y_est| (y_est - y_true).abs())
+            len_inv*y_true.comap(y_est, absolute_error)
                 .sum()
         ]
     }
@@ -26,21 +110,119 @@ where
         let len_inv = f!(Y; F).recip();
 
         [
-            y_true.comap(y_est, |y_true, y_est| len_inv.copysign(y_est - y_true))
+            y_true.comap(y_est, |y_true, y_est| len_inv*self.abs_grad(y_est - y_true))
         ]
     }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let len_inv = f!(Y; F).recip();
+
+        let e = y_true.comap(y_est, |y_true, y_est| y_est - y_true);
+
+        (
+            [len_inv*e.map(|e| e.abs()).sum()],
+            [e.map(|e| len_inv*self.abs_grad(e))]
+        )
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for WithSubgradient<MeanAbsoluteError>
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, absolute_error)
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for WithSubgradient<MeanAbsoluteError>
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| absolute_error(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss_grad = len_inv*self.abs_grad(y_est - y_true);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for MeanAbsoluteError
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
 }
 
 #[cfg(test)]
 mod test
 {
-    use crate::tests as t;
+    use crate::{tests as t, LossFunction, Subgradient};
     use super::MeanAbsoluteError as LF;
 
     #[test]
     fn test()
     {
         t::test(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.5, 4.0, 5.0, 4.0]);
         t::graph_2d(LF, [0.0, 0.0], -1.0..1.0);
+
+        for (subgradient, l_grad) in [(Subgradient::Left, -0.5), (Subgradient::Right, 0.5), (Subgradient::Zero, 0.0), (Subgradient::Midpoint, 0.0)]
+        {
+            assert_eq!(LF.with_subgradient(subgradient).lf_loss_grad([1.0, 2.0], [1.0, 3.0]), [[l_grad, 0.5]]);
+        }
+        assert_eq!(LF.lf_loss_grad([1.0, 2.0], [1.0, 3.0]), [[0.5, 0.5]]);
     }
 }
\ No newline at end of file
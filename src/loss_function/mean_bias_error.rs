@@ -8,6 +8,13 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct MeanBiasError;
 
+fn bias<F>(y_true: F, y_est: F) -> F
+where
+    F: Float
+{
+    y_est - y_true
+}
+
 impl<F, const Y: usize> LossFunction<F, Y, Y> for MeanBiasError
 where
     F: Float + AddAssign + ZeroConst
@@ -17,7 +24,7 @@ where
         let len_inv = f!(Y; F).recip();
         
         [
-            len_inv*y_true.comap(y_est, |y_true, y_est| y_est - y_true)
+            len_inv*y_true.comap(y_est, bias)
                 .sum()
         ]
     }
@@ -27,6 +34,91 @@ where
 
         [[len_inv; Y]]
     }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let len_inv = f!(Y; F).recip();
+
+        (
+            [len_inv*y_true.comap(y_est, bias).sum()],
+            [[len_inv; Y]]
+        )
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for MeanBiasError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, bias)
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for MeanBiasError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| bias(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss_grad.fill(len_inv);
+        Ok(())
+    }
+}
+
+impl LossProperties for MeanBiasError
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(0.0)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -39,6 +131,8 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
         t::graph_2d(LF, [0.3, -0.1], -1.0..1.0);
     }
 }
\ No newline at end of file
@@ -0,0 +1,204 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+use super::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct MeanHuberError
+{
+    pub delta: f64
+}
+
+impl MeanHuberError
+{
+    pub fn new(delta: f64) -> Self
+    {
+        Self {
+            delta
+        }
+    }
+
+    fn huber<F>(&self, y_true: F, y_est: F) -> F
+    where
+        F: Float
+    {
+        let delta = f!(self.delta; F);
+
+        let e = y_est - y_true;
+        if e.abs() <= delta
+        {
+            e*e*f!(0.5)
+        }
+        else
+        {
+            delta*(e.abs() - delta*f!(0.5))
+        }
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y> for MeanHuberError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.huber(y_true, y_est))
+                .sum()
+        ]
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+        let delta = f!(self.delta; F);
+
+        [
+            y_true.comap(y_est, |y_true, y_est| len_inv*(y_est - y_true).max(-delta).min(delta))
+        ]
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let len_inv = f!(Y; F).recip();
+        let delta = f!(self.delta; F);
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| {
+            let e = y_est - y_true;
+            if e.abs() <= delta
+            {
+                (e*e*f!(0.5), e)
+            }
+            else
+            {
+                (delta*(e.abs() - delta*f!(0.5)), delta.copysign(e))
+            }
+        });
+
+        (
+            [len_inv*l_and_grad.map(|(l, _)| l).sum()],
+            [l_and_grad.map(|(_, l_grad)| len_inv*l_grad)]
+        )
+    }
+}
+
+impl<F, const Y: usize> LossFunctionHessian<F, Y, Y> for MeanHuberError
+where
+    F: Float + AddAssign + ZeroConst + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y], y_est: [F; Y]) -> [[[F; Y]; Y]; 1]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+        let delta = f!(self.delta; F);
+
+        [
+            y_true.comap(y_est, |y_true, y_est| if (y_est - y_true).abs() <= delta {len_inv} else {F::zero()})
+        ]
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y> for MeanHuberError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let len_inv = f!(Y; F).recip();
+
+        [
+            len_inv*y_true.comap(y_est, |y_true, y_est| self.huber(y_true, y_est))
+                .comap(weights, |l, w| l*w)
+                .sum()
+        ]
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.comap(weights, |l_grad, w| l_grad*w))
+    }
+}
+
+impl<F> DynLossFunction<F> for MeanHuberError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+
+        loss[0] = len_inv*y_true.iter()
+            .zip(y_est)
+            .map(|(&y_true, &y_est)| self.huber(y_true, y_est))
+            .fold(F::zero(), |a, b| a + b);
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let len_inv = f!(y_est.len(); F).recip();
+        let delta = f!(self.delta; F);
+
+        for ((loss_grad, &y_true), &y_est) in loss_grad.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss_grad = len_inv*(y_est - y_true).max(-delta).min(delta);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for MeanHuberError
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.delta)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+    use super::MeanHuberError as LF;
+
+    #[test]
+    fn test()
+    {
+        let delta = 0.5;
+
+        t::test(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::test_hessian(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.0, 4.0, 5.0, 4.0]);
+        t::graph_2d(LF::new(delta), [0.0, 0.0], -1.0..1.0);
+    }
+}
@@ -1,19 +1,49 @@
+use num::Float;
+
 use super::*;
 
 moddef::moddef!(
     flat(pub) mod {
+        loss_function_error,
+        loss_function_hessian,
+        dyn_loss_function,
+        reduction,
+        subgradient,
+        batch_loss_function,
+        gradient_check,
+        auto_diff,
+        weighted_loss_function,
+        class_weighted,
+        loss_properties,
+
         square_error_loss,
+        huber_loss,
+        pseudo_huber_loss,
         one_zero_loss,
+        smooth_one_zero_loss,
         two_parameter_loss,
+        quantile_loss,
+        multi_quantile_loss,
 
         mean_square_error,
+        mean_huber_error,
+        mean_pseudo_huber_error,
         mean_absolute_error,
         mean_bias_error,
 
-        binary_cross_entropy_loss
+        binary_cross_entropy_loss,
+        binary_cross_entropy_with_logits,
+        categorical_cross_entropy_loss,
+        sparse_categorical_cross_entropy_loss,
+        binary_focal_loss,
+        binary_focal_loss_with_logits,
+        focal_loss,
+        focal_loss_with_logits
     },
     pub mod {
-        multi_class
+        multi_class,
+        combinator,
+        likelihood
     }
 );
 
@@ -21,4 +51,36 @@ pub trait LossFunction<F, const Y_TRUE: usize, const Y_EST: usize, const L: usiz
 {
     fn lf_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [F; L];
     fn lf_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> [[F; Y_EST]; L];
-}
\ No newline at end of file
+
+    /// Computes both the loss and its gradient, sharing any intermediate terms between them.
+    fn lf_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> ([F; L], [[F; Y_EST]; L])
+    where
+        F: Copy
+    {
+        (self.lf_loss(y_true, y_est), self.lf_loss_grad(y_true, y_est))
+    }
+}
+
+/// The logistic sigmoid `1/(1 + exp(-x))`, computed so that `exp` never overflows.
+pub(crate) fn sigmoid<F>(x: F) -> F
+where
+    F: Float
+{
+    let exp = (-x.abs()).exp();
+    if x >= F::zero()
+    {
+        (F::one() + exp).recip()
+    }
+    else
+    {
+        exp/(F::one() + exp)
+    }
+}
+
+/// The softplus `ln(1 + exp(x))`, computed so that `exp` never overflows.
+pub(crate) fn softplus<F>(x: F) -> F
+where
+    F: Float
+{
+    x.max(F::zero()) + (-x.abs()).exp().ln_1p()
+}
@@ -1,5 +1,3 @@
-use std::ops::AddAssign;
-
 use num::Float;
 
 use super::*;
@@ -7,19 +5,76 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct ExponentialLoss;
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for ExponentialLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl MarginLoss for ExponentialLoss
+{
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        (-z).exp()
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        -(-z).exp()
+    }
+    fn phi_and_grad<F>(&self, z: F) -> (F, F)
+    where
+        F: Float
+    {
+        let exp = (-z).exp();
+        (exp, -exp)
+    }
+}
+
+impl MarginLossHessian for ExponentialLoss
+{
+    fn phi_hessian<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        (-z).exp()
+    }
+}
+
+impl ProperCompositeLoss for ExponentialLoss
+{
+    fn link<F>(&self, p: F) -> F
+    where
+        F: Float
+    {
+        (p/(F::one() - p)).ln()/f!(2.0; F)
+    }
+    fn inverse_link<F>(&self, v: F) -> F
+    where
+        F: Float
+    {
+        (F::one() + (-f!(2.0; F)*v).exp()).recip()
+    }
+}
+
+impl LossProperties for ExponentialLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
     {
-        y_true.comap(y_est, |y_true, y_est| (-y_true*y_est).exp())
+        None
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    fn lf_bound(&self) -> Option<f64>
     {
-        y_true.comap(y_est, |y_true, y_est| -y_true*(-y_true*y_est).exp())
-            .diagonal()
+        None
     }
 }
 
@@ -33,6 +88,9 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
         t::graph_2d(LF, [0.8, 0.2], -1.0..1.0);
     }
 }
\ No newline at end of file
@@ -1,5 +1,3 @@
-use std::ops::AddAssign;
-
 use num::Float;
 
 use super::*;
@@ -20,62 +18,123 @@ impl GeneralizedSmoothHingeLoss
     }
 }
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for GeneralizedSmoothHingeLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl MarginLoss for GeneralizedSmoothHingeLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        let zero = F::zero();
+        let one = F::one();
+        let alpha = f!(self.alpha; F);
+
+        if z >= one
+        {
+            zero
+        }
+        else if z > zero
+        {
+            (alpha + z.powf(alpha + one))/(alpha + one) - z
+        }
+        else
+        {
+            alpha/(alpha + one) - z
+        }
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        let zero = F::zero();
+        let one = F::one();
+        let alpha = f!(self.alpha; F);
+
+        if z >= one
+        {
+            zero
+        }
+        else if z > zero
+        {
+            z.powf(alpha) - one
+        }
+        else
+        {
+            -one
+        }
+    }
+    fn phi_and_grad<F>(&self, z: F) -> (F, F)
+    where
+        F: Float
     {
         let zero = F::zero();
         let one = F::one();
         let alpha = f!(self.alpha; F);
 
-        y_true.comap(y_est, |y_true, y_est| {
-            let z = y_true*y_est;
-            if z >= one
-            {
-                zero
-            }
-            else if z > zero
-            {
-                (alpha + z.powf(alpha + one))/(alpha + one) - z
-            }
-            else
-            {
-                alpha/(alpha + one) - z
-            }
-        })
+        if z >= one
+        {
+            (zero, zero)
+        }
+        else if z > zero
+        {
+            let z_pow_alpha = z.powf(alpha);
+            ((alpha + z_pow_alpha*z)/(alpha + one) - z, z_pow_alpha - one)
+        }
+        else
+        {
+            (alpha/(alpha + one) - z, -one)
+        }
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+}
+
+impl MarginLossHessian for GeneralizedSmoothHingeLoss
+{
+    fn phi_hessian<F>(&self, z: F) -> F
+    where
+        F: Float
     {
         let zero = F::zero();
         let one = F::one();
         let alpha = f!(self.alpha; F);
 
-        y_true.comap(y_est, |y_true, y_est| {
-            let z = y_true*y_est;
-            if z >= one
-            {
-                zero
-            }
-            else if z > zero
-            {
-                z.powf(alpha) - one
-            }
-            else
-            {
-                -one
-            }
-        })
-            .diagonal()
+        if z > zero && z < one
+        {
+            alpha*z.powf(alpha - one)
+        }
+        else
+        {
+            zero
+        }
+    }
+}
+
+impl LossProperties for GeneralizedSmoothHingeLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        (self.alpha >= 1.0).then(|| self.alpha)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
     }
 }
 
 #[cfg(test)]
 mod test
 {
-    use crate::tests as t;
+    use crate::{tests as t, LossFunction};
     use super::GeneralizedSmoothHingeLoss as LF;
 
     #[test]
@@ -83,7 +142,25 @@ mod test
     {
         let alpha = 0.5;
 
-        t::test(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -0.4, 0.8, 1.5, 0.3]);
+        t::test_dyn(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -0.4, 0.8, 1.5, 0.3]);
+        t::test_gradient(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -0.4, 0.8, 1.5, 0.3]);
+        t::test_hessian(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -0.4, 0.8, 1.5, 0.3]);
         t::graph_2d(LF::new(alpha), [0.8, 0.2], -1.0..1.0);
+
+        // Margins all at least one for the negative labels
+        t::test(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(LF::new(alpha), [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+    }
+
+    #[test]
+    fn test_negative_label_grad()
+    {
+        // The gradient carries the sign of the label, both inside the smooth region and on the linear part
+        let l_grad = LF::new(0.5).lf_loss_grad([-1.0, -1.0], [-0.25, 0.3]);
+
+        assert!((l_grad[0][0] - 0.5).abs() < 1e-12, "{:?}", l_grad);
+        assert!((l_grad[1][1] - 1.0).abs() < 1e-12, "{:?}", l_grad);
     }
 }
\ No newline at end of file
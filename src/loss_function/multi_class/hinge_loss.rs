@@ -1,44 +1,108 @@
-use std::ops::AddAssign;
-
 use num::Float;
 
 use super::*;
 
+/// The hinge loss, `max(1 - z, 0)` of the margin `z = y_true*y_est`.
+/// 
+/// At the kink, where `z == 1`, the gradient is the one from the left. Use [with_subgradient](HingeLoss::with_subgradient) to pick another.
 #[derive(Clone, Copy, Debug)]
 pub struct HingeLoss;
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for HingeLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl HingeLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    pub fn with_subgradient(self, subgradient: Subgradient) -> WithSubgradient<Self>
     {
-        let zero = F::zero();
-        let one = F::one();
+        WithSubgradient::new(self, subgradient)
+    }
+}
+
+impl MarginLoss for HingeLoss
+{
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        (F::one() - z).max(F::zero())
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        if z <= F::one()
+        {
+            -F::one()
+        }
+        else
+        {
+            F::zero()
+        }
+    }
+}
 
-        y_true.comap(y_est, |y_true, y_est| (one - y_true*y_est).max(zero))
+impl MarginLoss for WithSubgradient<HingeLoss>
+{
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        self.loss.phi(z)
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
     {
         let zero = F::zero();
         let one = F::one();
 
-        y_true.comap(y_est, |y_true, y_est| if y_true*y_est <= one {-y_true} else {zero})
-            .diagonal()
+        self.subgradient.pick(
+            if z > one {zero} else {-one},
+            if z < one {-one} else {zero}
+        )
+    }
+}
+
+impl LossProperties for HingeLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
     }
 }
 
 #[cfg(test)]
 mod test
 {
-    use crate::tests as t;
+    use crate::{tests as t, LossFunction, Subgradient};
     use super::HingeLoss as LF;
 
     #[test]
     fn test()
     {
         t::test(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
         t::graph_2d(LF, [0.8, 0.2], -1.0..1.0);
+
+        for (subgradient, l_grad) in [(Subgradient::Left, -1.0), (Subgradient::Right, 0.0), (Subgradient::Zero, 0.0), (Subgradient::Midpoint, -0.5)]
+        {
+            assert_eq!(LF.with_subgradient(subgradient).lf_loss_grad([1.0, -1.0], [1.0, -1.0]), [[l_grad, 0.0], [0.0, -l_grad]]);
+        }
+        assert_eq!(LF.lf_loss_grad([1.0, -1.0], [1.0, -1.0]), [[-1.0, 0.0], [0.0, 1.0]]);
     }
 }
\ No newline at end of file
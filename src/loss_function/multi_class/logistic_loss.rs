@@ -1,4 +1,4 @@
-use std::ops::AddAssign;
+use std::f64::consts::LN_2;
 
 use num::Float;
 
@@ -7,23 +7,82 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct LogisticLoss;
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for LogisticLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl MarginLoss for LogisticLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        (F::one() + (-z).exp()).ln()/f!(2.0; F).ln()
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        -(f!(2.0; F).ln()*(z.exp() + F::one())).recip()
+    }
+    fn phi_and_grad<F>(&self, z: F) -> (F, F)
+    where
+        F: Float
     {
         let one = F::one();
+        let ln2 = f!(2.0; F).ln();
 
-        y_true.comap(y_est, |y_true, y_est| (one + (-y_true*y_est).exp()).ln()/f!(2.0; F).ln())
+        let exp = (-z).exp();
+        ((one + exp).ln()/ln2, -exp/(ln2*(one + exp)))
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+}
+
+impl MarginLossHessian for LogisticLoss
+{
+    fn phi_hessian<F>(&self, z: F) -> F
+    where
+        F: Float
     {
         let one = F::one();
 
-        y_true.comap(y_est, |y_true, y_est| -y_true/(f!(2.0; F).ln()*((y_true*y_est).exp() + one)))
-            .diagonal()
+        let exp = (-z.abs()).exp();
+        exp/(f!(2.0; F).ln()*(one + exp)*(one + exp))
+    }
+}
+
+impl ProperCompositeLoss for LogisticLoss
+{
+    fn link<F>(&self, p: F) -> F
+    where
+        F: Float
+    {
+        (p/(F::one() - p)).ln()
+    }
+    fn inverse_link<F>(&self, v: F) -> F
+    where
+        F: Float
+    {
+        (F::one() + (-v).exp()).recip()
+    }
+}
+
+impl LossProperties for LogisticLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(LN_2.recip())
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(0.25/LN_2)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
     }
 }
 
@@ -37,6 +96,9 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
         t::graph_2d(LF, [0.8, 0.2], -1.0..1.0);
     }
 }
\ No newline at end of file
@@ -0,0 +1,186 @@
+use num::Float;
+
+use super::*;
+
+/// A loss of the margin `z = y_true*y_est`, applied to each output, where `y_true` is `1` or `-1`.
+/// 
+/// Implementing `phi` and its derivative `phi_grad` is enough for a [LossFunction](LossFunction), with gradient `y_true*phi_grad(z)`.
+pub trait MarginLoss
+{
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float;
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float;
+
+    /// Computes both `phi` and its derivative, sharing any intermediate terms between them.
+    fn phi_and_grad<F>(&self, z: F) -> (F, F)
+    where
+        F: Float
+    {
+        (self.phi(z), self.phi_grad(z))
+    }
+}
+
+/// A margin loss with a known second derivative, giving the hessian `y_true*y_true*phi_hessian(z)`.
+pub trait MarginLossHessian: MarginLoss
+{
+    fn phi_hessian<F>(&self, z: F) -> F
+    where
+        F: Float;
+}
+
+impl<T, F, const Y: usize> LossFunction<F, Y, Y, Y> for T
+where
+    T: MarginLoss,
+    F: Float + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    {
+        y_true.comap(y_est, |y_true, y_est| self.phi(y_true*y_est))
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    {
+        y_true.comap(y_est, |y_true, y_est| y_true*self.phi_grad(y_true*y_est))
+            .diagonal()
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| {
+            let (l, l_grad) = self.phi_and_grad(y_true*y_est);
+            (l, y_true*l_grad)
+        });
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<T, F, const Y: usize> LossFunctionHessian<F, Y, Y, Y> for T
+where
+    T: MarginLossHessian,
+    F: Float + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; Y], y_est: [F; Y]) -> [[[F; Y]; Y]; Y]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    {
+        y_true.comap(y_est, |y_true, y_est| y_true*y_true*self.phi_hessian(y_true*y_est))
+            .diagonal()
+    }
+}
+
+impl<T, F, const Y: usize> WeightedLossFunction<F, Y, Y, Y> for T
+where
+    T: MarginLoss,
+    F: Float + Default,
+    [(); Y - Y]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; Y]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; Y]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<T, F> DynLossFunction<F> for T
+where
+    T: MarginLoss,
+    F: Float
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss = self.phi(y_true*y_est);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+        {
+            loss_grad[i*n + i] = y_true*self.phi_grad(y_true*y_est);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use num::Float;
+
+    use crate::tests as t;
+    use super::{MarginLoss, MarginLossHessian};
+
+    /// The sigmoid loss, `1 - tanh(z)`, which is only defined here through its scalar function.
+    #[derive(Clone, Copy, Debug)]
+    struct SigmoidLoss;
+
+    impl MarginLoss for SigmoidLoss
+    {
+        fn phi<F>(&self, z: F) -> F
+        where
+            F: Float
+        {
+            F::one() - z.tanh()
+        }
+        fn phi_grad<F>(&self, z: F) -> F
+        where
+            F: Float
+        {
+            let tanh = z.tanh();
+            tanh*tanh - F::one()
+        }
+    }
+
+    impl MarginLossHessian for SigmoidLoss
+    {
+        fn phi_hessian<F>(&self, z: F) -> F
+        where
+            F: Float
+        {
+            let tanh = z.tanh();
+            (F::one() - tanh*tanh)*(tanh + tanh)
+        }
+    }
+
+    #[test]
+    fn test()
+    {
+        t::test(SigmoidLoss, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(SigmoidLoss, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(SigmoidLoss, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(SigmoidLoss, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+    }
+}
@@ -1,5 +1,8 @@
 moddef::moddef!(
     flat(pub) mod {
+        margin_loss,
+        proper_composite_loss,
+
         exponential_loss,
         logistic_loss,
         square_loss,
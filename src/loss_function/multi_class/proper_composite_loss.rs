@@ -0,0 +1,63 @@
+use num::Float;
+
+use super::*;
+
+/// A [margin loss](MarginLoss) which is proper composite, so that a score `y_est` can be turned back into the probability of `y_true = 1`.
+/// 
+/// The link maps a probability `p` to the score minimizing the expected loss `p*phi(v) + (1 - p)*phi(-v)`, and the inverse link maps scores back to probabilities.
+pub trait ProperCompositeLoss: MarginLoss
+{
+    fn link<F>(&self, p: F) -> F
+    where
+        F: Float;
+    fn inverse_link<F>(&self, v: F) -> F
+    where
+        F: Float;
+
+    /// Estimates the probability of `y_true = 1` for each output.
+    fn predict_proba<F, const Y: usize>(&self, y_est: [F; Y]) -> [F; Y]
+    where
+        F: Float
+    {
+        y_est.map(|v| self.inverse_link(v))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::multi_class::{ExponentialLoss, LogisticLoss, SavageLoss, SquareLoss, TangentLoss};
+    use super::*;
+
+    fn test_link<T>(lf: T)
+    where
+        T: ProperCompositeLoss
+    {
+        const TOLERANCE: f64 = 1e-9;
+
+        for p in [0.1, 0.25, 0.5, 0.7, 0.95]
+        {
+            let v = lf.link(p);
+            assert!((lf.inverse_link(v) - p).abs() <= TOLERANCE, "Inverse link mismatch at p = {}", p);
+
+            // The expected loss is stationary at the link of the probability.
+            let risk_grad = p*lf.phi_grad(v) - (1.0 - p)*lf.phi_grad(-v);
+            assert!(risk_grad.abs() <= TOLERANCE, "Expected loss not minimized at p = {}: gradient {}", p, risk_grad);
+        }
+
+        let p = lf.predict_proba([-100.0, -1.0, 0.0, 1.0, 100.0]);
+        assert!(p.into_iter().all(|p| (0.0..=1.0).contains(&p)), "Probability out of range: {:?}", p);
+        assert!(p.windows(2).all(|p| p[0] <= p[1]), "Probability not monotonic: {:?}", p);
+        assert!((p[2] - 0.5).abs() <= TOLERANCE);
+    }
+
+    #[test]
+    fn test()
+    {
+        test_link(ExponentialLoss);
+        test_link(LogisticLoss);
+        test_link(SavageLoss);
+        test_link(SquareLoss);
+        test_link(TangentLoss);
+    }
+}
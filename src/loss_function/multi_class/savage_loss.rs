@@ -1,5 +1,3 @@
-use std::ops::AddAssign;
-
 use num::Float;
 
 use super::*;
@@ -7,30 +5,84 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct SavageLoss;
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for SavageLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl MarginLoss for SavageLoss
+{
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        (F::one() + z.exp()).powi(-2)
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        let exp = z.exp();
+        -f!(2.0; F)*exp*(F::one() + exp).powi(-3)
+    }
+    fn phi_and_grad<F>(&self, z: F) -> (F, F)
+    where
+        F: Float
+    {
+        let exp = z.exp();
+        let s = (F::one() + exp).recip();
+        let s2 = s*s;
+        (s2, -f!(2.0; F)*exp*s2*s)
+    }
+}
+
+impl MarginLossHessian for SavageLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    fn phi_hessian<F>(&self, z: F) -> F
+    where
+        F: Float
     {
-        let one = F::one();
+        let two = f!(2.0; F);
 
-        y_true.comap(y_est, |y_true, y_est| {
-            let sqrt = (one + (y_true*y_est).exp()).recip();
-            sqrt*sqrt
-        })
+        let exp = z.exp();
+        two*exp*(two*exp - F::one())*(F::one() + exp).powi(-4)
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+}
+
+impl ProperCompositeLoss for SavageLoss
+{
+    fn link<F>(&self, p: F) -> F
+    where
+        F: Float
     {
-        let one = F::one();
+        (p/(F::one() - p)).ln()
+    }
+    fn inverse_link<F>(&self, v: F) -> F
+    where
+        F: Float
+    {
+        (F::one() + (-v).exp()).recip()
+    }
+}
 
-        y_true.comap(y_est, |y_true, y_est| {
-            let exp = (y_true*y_est).exp();
-            let cbrt = (one + exp).recip();
-            y_true*cbrt*cbrt*cbrt*f!(-2.0)*exp
-        })
-            .diagonal()
+impl LossProperties for SavageLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(8.0/27.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        // The second derivative peaks at exp(z) = (7 + sqrt(33))/8.
+        let exp = (7.0 + 33f64.sqrt())/8.0;
+        Some(2.0*exp*(2.0*exp - 1.0)/(1.0 + exp).powi(4))
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        Some(1.0)
     }
 }
 
@@ -44,6 +96,9 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
         t::graph_2d(LF, [0.8, 0.2], -1.0..1.0);
     }
 }
\ No newline at end of file
@@ -1,5 +1,3 @@
-use std::ops::AddAssign;
-
 use num::Float;
 
 use super::*;
@@ -7,26 +5,70 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct SquareLoss;
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for SquareLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl MarginLoss for SquareLoss
+{
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        let d = F::one() - z;
+        d*d
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        -f!(2.0; F)*(F::one() - z)
+    }
+}
+
+impl MarginLossHessian for SquareLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    fn phi_hessian<F>(&self, _z: F) -> F
+    where
+        F: Float
     {
-        let one = F::one();
+        f!(2.0; F)
+    }
+}
 
-        y_true.comap(y_est, |y_true, y_est| {
-            let sqrt = one - y_true*y_est;
-            sqrt*sqrt
-        })
+impl ProperCompositeLoss for SquareLoss
+{
+    fn link<F>(&self, p: F) -> F
+    where
+        F: Float
+    {
+        f!(2.0; F)*p - F::one()
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    fn inverse_link<F>(&self, v: F) -> F
+    where
+        F: Float
     {
-        let one = F::one();
+        ((F::one() + v)/f!(2.0; F)).max(F::zero()).min(F::one())
+    }
+}
 
-        y_true.comap(y_est, |y_true, y_est| -y_true*f!(2.0)*(one - y_true*y_est))
-            .diagonal()
+impl LossProperties for SquareLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(2.0)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
     }
 }
 
@@ -40,6 +82,9 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
         t::graph_2d(LF, [0.8, 0.2], -1.0..1.0);
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::ops::AddAssign;
+use std::f64::consts::PI;
 
 use num::Float;
 
@@ -7,26 +7,75 @@ use super::*;
 #[derive(Clone, Copy, Debug)]
 pub struct TangentLoss;
 
-impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for TangentLoss
-where
-    F: Float + AddAssign + Default,
-    [(); Y - Y]:
+impl MarginLoss for TangentLoss
 {
-    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    fn phi<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        let d = f!(2.0; F)*z.atan() - F::one();
+        d*d
+    }
+    fn phi_grad<F>(&self, z: F) -> F
+    where
+        F: Float
+    {
+        f!(4.0; F)*(f!(2.0; F)*z.atan() - F::one())/(F::one() + z*z)
+    }
+}
+
+impl MarginLossHessian for TangentLoss
+{
+    fn phi_hessian<F>(&self, z: F) -> F
+    where
+        F: Float
     {
         let one = F::one();
+        let d = one + z*z;
 
-        y_true.comap(y_est, |y_true, y_est| {
-            let sqrt = (y_true*y_est).atan()*f!(2.0) - one;
-            sqrt*sqrt
-        })
+        (f!(8.0; F) - f!(2.0; F)*z*(f!(8.0; F)*z.atan() - f!(4.0; F)))/(d*d)
     }
-    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+}
+
+impl ProperCompositeLoss for TangentLoss
+{
+    fn link<F>(&self, p: F) -> F
+    where
+        F: Float
     {
-        let one = F::one();
+        (p - f!(0.5; F)).tan()
+    }
+    fn inverse_link<F>(&self, v: F) -> F
+    where
+        F: Float
+    {
+        (v.atan() + f!(0.5; F)).max(F::zero()).min(F::one())
+    }
+}
 
-        y_true.comap(y_est, |y_true, y_est| y_true*((y_true*y_est).atan()*f!(8.0) - f!(4.0))/(one + y_true*y_true*y_est*y_est))
-            .diagonal()
+impl LossProperties for TangentLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        // Maximum of the first derivative, at z ≈ -0.5132, rounded up.
+        Some(6.1686)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        // Maximum of the second derivative, at z ≈ 0.1210, rounded up.
+        Some(8.4847)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        Some((PI + 1.0)*(PI + 1.0))
     }
 }
 
@@ -40,6 +89,9 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_dyn(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_gradient(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
+        t::test_hessian(LF, [1.0, -1.0, 1.0, 1.0, -1.0], [0.6, -1.2, 0.8, 1.5, -1.3]);
         t::graph_2d(LF, [0.8, 0.2], -1.0..1.0);
     }
 }
\ No newline at end of file
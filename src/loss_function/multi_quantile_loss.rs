@@ -0,0 +1,227 @@
+use num::Float;
+use super::*;
+
+/// The pinball loss of several quantiles of a single target at once, where `y_est[q]` estimates the `taus[q]`-quantile.
+/// 
+/// At the kink, where `y_est[q] == y_true[0]`, the gradient is the midpoint of the ones from the left and right, `0.5 - taus[q]`. Use [with_subgradient](MultiQuantileLoss::with_subgradient) to pick another.
+#[derive(Clone, Copy, Debug)]
+pub struct MultiQuantileLoss<const Q: usize>
+{
+    pub taus: [f64; Q]
+}
+
+impl<const Q: usize> MultiQuantileLoss<Q>
+{
+    pub fn new(taus: [f64; Q]) -> Self
+    {
+        Self {
+            taus
+        }
+    }
+
+    pub fn with_subgradient(self, subgradient: Subgradient) -> WithSubgradient<Self>
+    {
+        WithSubgradient::new(self, subgradient)
+    }
+}
+
+impl<F, const Q: usize> LossFunction<F, 1, Q, Q> for MultiQuantileLoss<Q>
+where
+    F: Float + Default,
+    [(); Q - Q]:
+{
+    fn lf_loss(&self, y_true: [F; 1], y_est: [F; Q]) -> [F; Q]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_loss(y_true, y_est)
+    }
+    fn lf_loss_grad(&self, y_true: [F; 1], y_est: [F; Q]) -> [[F; Q]; Q]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_loss_grad(y_true, y_est)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; 1], y_est: [F; Q]) -> ([F; Q], [[F; Q]; Q])
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_loss_and_grad(y_true, y_est)
+    }
+}
+
+impl<F, const Q: usize> WeightedLossFunction<F, 1, Q, Q> for MultiQuantileLoss<Q>
+where
+    F: Float + Default,
+    [(); Q - Q]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; 1], y_est: [F; Q], weights: [F; Q]) -> [F; Q]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_weighted_loss(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; 1], y_est: [F; Q], weights: [F; Q]) -> [[F; Q]; Q]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_weighted_loss_grad(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; 1], y_est: [F; Q], weights: [F; Q]) -> ([F; Q], [[F; Q]; Q])
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_weighted_loss_and_grad(y_true, y_est, weights)
+    }
+}
+
+impl<F, const Q: usize> DynLossFunction<F> for MultiQuantileLoss<Q>
+where
+    F: Float + Default
+{
+    fn lf_dyn_y_true_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        Q
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_dyn_loss(y_true, y_est, loss)
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_dyn_loss_grad(y_true, y_est, loss_grad)
+    }
+}
+
+impl<F, const Q: usize> LossFunction<F, 1, Q, Q> for WithSubgradient<MultiQuantileLoss<Q>>
+where
+    F: Float + Default,
+    [(); Q - Q]:
+{
+    fn lf_loss(&self, [y_true]: [F; 1], y_est: [F; Q]) -> [F; Q]
+    {
+        self.loss.taus.comap(y_est, |tau, y_est| quantile(tau, y_true, y_est))
+    }
+    fn lf_loss_grad(&self, [y_true]: [F; 1], y_est: [F; Q]) -> [[F; Q]; Q]
+    {
+        self.loss.taus.comap(y_est, |tau, y_est| quantile_grad(tau, self.subgradient, y_true, y_est))
+            .diagonal()
+    }
+    fn lf_loss_and_grad(&self, [y_true]: [F; 1], y_est: [F; Q]) -> ([F; Q], [[F; Q]; Q])
+    {
+        let l_and_grad = self.loss.taus.comap(y_est, |tau, y_est| (
+            quantile(tau, y_true, y_est),
+            quantile_grad(tau, self.subgradient, y_true, y_est)
+        ));
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const Q: usize> WeightedLossFunction<F, 1, Q, Q> for WithSubgradient<MultiQuantileLoss<Q>>
+where
+    F: Float + Default,
+    [(); Q - Q]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; 1], y_est: [F; Q], weights: [F; Q]) -> [F; Q]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; 1], y_est: [F; Q], weights: [F; Q]) -> [[F; Q]; Q]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; 1], y_est: [F; Q], weights: [F; Q]) -> ([F; Q], [[F; Q]; Q])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F, const Q: usize> DynLossFunction<F> for WithSubgradient<MultiQuantileLoss<Q>>
+where
+    F: Float + Default
+{
+    fn lf_dyn_y_true_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        Q
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        LossFunctionError::check_y_est_len(Q, y_est.len())?;
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        for ((loss, &tau), &y_est) in loss.iter_mut().zip(&self.loss.taus).zip(y_est)
+        {
+            *loss = quantile(tau, y_true[0], y_est);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        LossFunctionError::check_y_est_len(Q, y_est.len())?;
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        loss_grad.fill(F::zero());
+        for (i, (&tau, &y_est)) in self.loss.taus.iter().zip(y_est).enumerate()
+        {
+            loss_grad[i*Q + i] = quantile_grad(tau, self.subgradient, y_true[0], y_est);
+        }
+        Ok(())
+    }
+}
+
+impl<const Q: usize> LossProperties for MultiQuantileLoss<Q>
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(
+            self.taus.into_iter()
+                .map(|tau| tau.max(1.0 - tau))
+                .fold(0.0, f64::max)
+        )
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, LossFunction, Subgradient};
+    use super::MultiQuantileLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let taus = [0.1, 0.5, 0.9];
+
+        t::test(LF::new(taus), [2.0], [1.0, 2.0, 3.5]);
+        t::test_dyn(LF::new(taus), [2.0], [1.0, 2.0, 3.5]);
+        t::test_gradient(LF::new(taus), [2.0], [1.0, 2.5, 3.5]);
+
+        for (subgradient, l_grad) in [(Subgradient::Left, -0.5), (Subgradient::Right, 0.5), (Subgradient::Zero, 0.0), (Subgradient::Midpoint, 0.0)]
+        {
+            let l_grad_kink = LF::new(taus).with_subgradient(subgradient).lf_loss_grad([2.0], [1.0, 2.0, 3.5])[1][1];
+            assert!((l_grad_kink - l_grad).abs() < 1e-12, "Subgradient mismatch for {:?}: {} != {}", subgradient, l_grad_kink, l_grad);
+        }
+        let l_grad_kink = LF::new(taus).lf_loss_grad([2.0], [1.0, 2.0, 3.5])[1][1];
+        assert!(l_grad_kink.abs() < 1e-12, "Default subgradient mismatch: {} != 0", l_grad_kink);
+    }
+}
@@ -3,33 +3,244 @@ use std::ops::AddAssign;
 use num::Float;
 use super::*;
 
+/// Loss of one for each estimate not equal to its target, or where either is NaN, and zero otherwise.
+/// 
+/// The gradient is zero everywhere. [SmoothOneZeroLoss](SmoothOneZeroLoss) is a differentiable surrogate.
+/// Use [with_tolerance](OneZeroLoss::with_tolerance) to count estimates within a tolerance of their target as correct.
 #[derive(Clone, Copy, Debug)]
 pub struct OneZeroLoss;
 
+impl OneZeroLoss
+{
+    pub fn with_tolerance(self, tolerance: f64) -> WithTolerance<Self>
+    {
+        WithTolerance::new(self, tolerance)
+    }
+}
+
+/// A loss which counts estimates no further than `tolerance` from their target as exact.
+/// 
+/// Build it with the `with_tolerance` method of the loss.
+#[derive(Clone, Copy, Debug)]
+pub struct WithTolerance<L>
+{
+    pub loss: L,
+    pub tolerance: f64
+}
+
+impl<L> WithTolerance<L>
+{
+    pub fn new(loss: L, tolerance: f64) -> Self
+    {
+        Self {
+            loss,
+            tolerance
+        }
+    }
+}
+
+// Negated so that NaN is never within the tolerance
+#[allow(clippy::neg_cmp_op_on_partial_ord)]
+fn is_wrong<F>(y_true: F, y_est: F, tolerance: F) -> bool
+where
+    F: Float
+{
+    !((y_est - y_true).abs() <= tolerance)
+}
+
 impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for OneZeroLoss
 where
     F: Float + AddAssign + Default
 {
     fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
     {
-        y_true.comap(y_est, |y_true, y_est| f!((y_est != y_true) as u8))
+        self.with_tolerance(0.0).lf_loss(y_true, y_est)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    {
+        self.with_tolerance(0.0).lf_loss_grad(y_true, y_est)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        self.with_tolerance(0.0).lf_loss_and_grad(y_true, y_est)
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y, Y> for OneZeroLoss
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; Y]
+    {
+        self.with_tolerance(0.0).lf_weighted_loss(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; Y]
+    {
+        self.with_tolerance(0.0).lf_weighted_loss_grad(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        self.with_tolerance(0.0).lf_weighted_loss_and_grad(y_true, y_est, weights)
+    }
+}
+
+impl<F> DynLossFunction<F> for OneZeroLoss
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_tolerance(0.0).lf_dyn_loss(y_true, y_est, loss)
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_tolerance(0.0).lf_dyn_loss_grad(y_true, y_est, loss_grad)
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for WithTolerance<OneZeroLoss>
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    {
+        let tolerance = f!(self.tolerance; F);
+
+        y_true.comap(y_est, |y_true, y_est| f!(is_wrong(y_true, y_est, tolerance) as u8))
     }
     fn lf_loss_grad(&self, _y_true: [F; Y], _y_est: [F; Y]) -> [[F; Y]; Y]
     {
         [[F::zero(); Y]; Y]
     }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        (self.lf_loss(y_true, y_est), [[F::zero(); Y]; Y])
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y, Y> for WithTolerance<OneZeroLoss>
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; Y]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; Y]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for WithTolerance<OneZeroLoss>
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let tolerance = f!(self.tolerance; F);
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss = f!(is_wrong(y_true, y_est, tolerance) as u8);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        loss_grad.fill(F::zero());
+        Ok(())
+    }
+}
+
+impl<L> LossProperties for WithTolerance<L>
+where
+    L: LossProperties
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.loss.lf_is_convex()
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.loss.lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        self.loss.lf_lipschitz()
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        self.loss.lf_smoothness()
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        self.loss.lf_bound()
+    }
+}
+
+impl LossProperties for OneZeroLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
 }
 
 #[cfg(test)]
 mod test
 {
-    use crate::tests as t;
+    use crate::{tests as t, LossFunction};
     use super::OneZeroLoss as LF;
 
     #[test]
     fn test()
     {
         t::test(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.5, 4.0, 5.0, 4.0]);
         t::graph_2d(LF, [0.3, -0.1], -1.0..1.0);
+
+        assert_eq!(LF.with_tolerance(0.1).lf_loss([1.0, 2.0, 3.0], [1.05, 2.5, 2.95]), [0.0, 1.0, 0.0]);
+        assert_eq!(LF.with_tolerance(0.1).lf_loss([1.0, f64::NAN], [f64::NAN, 2.0]), [1.0, 1.0]);
+        assert_eq!(LF.lf_loss([1.0, f64::NAN], [f64::NAN, 2.0]), [1.0, 1.0]);
     }
 }
\ No newline at end of file
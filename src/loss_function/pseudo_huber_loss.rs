@@ -0,0 +1,190 @@
+use num::Float;
+use super::*;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PseudoHuberLoss
+{
+    pub delta: f64
+}
+
+impl PseudoHuberLoss
+{
+    pub fn new(delta: f64) -> Self
+    {
+        Self {
+            delta
+        }
+    }
+}
+
+impl<F, const N: usize> LossFunction<F, N, N, N> for PseudoHuberLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss(&self, y_true: [F; N], y_est: [F; N]) -> [F; N]
+    {
+        let one = F::one();
+        let delta = f!(self.delta; F);
+
+        y_true.comap(y_est, |y_true, y_est| {
+            let e = (y_est - y_true)/delta;
+            delta*delta*((one + e*e).sqrt() - one)
+        })
+    }
+    fn lf_loss_grad(&self, y_true: [F; N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        let one = F::one();
+        let delta = f!(self.delta; F);
+
+        y_true.comap(y_est, |y_true, y_est| {
+            let e = (y_est - y_true)/delta;
+            delta*e/(one + e*e).sqrt()
+        })
+            .diagonal()
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let one = F::one();
+        let delta = f!(self.delta; F);
+
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| {
+            let e = (y_est - y_true)/delta;
+            let sqrt = (one + e*e).sqrt();
+            (delta*delta*(sqrt - one), delta*e/sqrt)
+        });
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const N: usize> LossFunctionHessian<F, N, N, N> for PseudoHuberLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; N], y_est: [F; N]) -> [[[F; N]; N]; N]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, y_true: [F; N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        let one = F::one();
+        let delta = f!(self.delta; F);
+
+        y_true.comap(y_est, |y_true, y_est| {
+            let e = (y_est - y_true)/delta;
+            let sqrt = (one + e*e).sqrt();
+            (sqrt*sqrt*sqrt).recip()
+        })
+            .diagonal()
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, N, N, N> for PseudoHuberLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for PseudoHuberLoss
+where
+    F: Float + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let one = F::one();
+        let delta = f!(self.delta; F);
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            let e = (y_est - y_true)/delta;
+            *loss = delta*delta*((one + e*e).sqrt() - one);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let one = F::one();
+        let delta = f!(self.delta; F);
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+        {
+            let e = (y_est - y_true)/delta;
+            loss_grad[i*n + i] = delta*e/(one + e*e).sqrt();
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for PseudoHuberLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.delta)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+    use super::PseudoHuberLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let delta = 0.5;
+
+        t::test(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_hessian(LF::new(delta), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::graph_2d(LF::new(delta), [0.3, -0.1], -1.0..1.0);
+    }
+}
@@ -0,0 +1,241 @@
+use num::Float;
+use super::*;
+
+/// The pinball loss of the `tau`-quantile.
+/// 
+/// At the kink, where `y_est == y_true`, the gradient is the midpoint of the ones from the left and right, `0.5 - tau`. Use [with_subgradient](QuantileLoss::with_subgradient) to pick another.
+#[derive(Clone, Copy, Debug)]
+pub struct QuantileLoss
+{
+    pub tau: f64
+}
+
+impl QuantileLoss
+{
+    pub fn new(tau: f64) -> Self
+    {
+        Self {
+            tau
+        }
+    }
+
+    pub fn with_subgradient(self, subgradient: Subgradient) -> WithSubgradient<Self>
+    {
+        WithSubgradient::new(self, subgradient)
+    }
+}
+
+/// The pinball loss of the `tau`-quantile.
+pub(crate) fn quantile<F>(tau: f64, y_true: F, y_est: F) -> F
+where
+    F: Float
+{
+    let tau = f!(tau; F);
+
+    let e = y_true - y_est;
+    (tau*e).max((tau - F::one())*e)
+}
+
+/// Gradient of the pinball loss of the `tau`-quantile, with `subgradient` picking it at the kink.
+pub(crate) fn quantile_grad<F>(tau: f64, subgradient: Subgradient, y_true: F, y_est: F) -> F
+where
+    F: Float
+{
+    let one = F::one();
+    let tau = f!(tau; F);
+
+    let e = y_est - y_true;
+    subgradient.pick(
+        if e > F::zero() {one - tau} else {-tau},
+        if e < F::zero() {-tau} else {one - tau}
+    )
+}
+
+impl<F, const N: usize> LossFunction<F, N, N, N> for QuantileLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss(&self, y_true: [F; N], y_est: [F; N]) -> [F; N]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_loss(y_true, y_est)
+    }
+    fn lf_loss_grad(&self, y_true: [F; N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_loss_grad(y_true, y_est)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_loss_and_grad(y_true, y_est)
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, N, N, N> for QuantileLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_weighted_loss(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_weighted_loss_grad(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_weighted_loss_and_grad(y_true, y_est, weights)
+    }
+}
+
+impl<F> DynLossFunction<F> for QuantileLoss
+where
+    F: Float + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_dyn_loss(y_true, y_est, loss)
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Midpoint).lf_dyn_loss_grad(y_true, y_est, loss_grad)
+    }
+}
+
+impl<F, const N: usize> LossFunction<F, N, N, N> for WithSubgradient<QuantileLoss>
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss(&self, y_true: [F; N], y_est: [F; N]) -> [F; N]
+    {
+        y_true.comap(y_est, |y_true, y_est| quantile(self.loss.tau, y_true, y_est))
+    }
+    fn lf_loss_grad(&self, y_true: [F; N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        y_true.comap(y_est, |y_true, y_est| quantile_grad(self.loss.tau, self.subgradient, y_true, y_est))
+            .diagonal()
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| (
+            quantile(self.loss.tau, y_true, y_est),
+            quantile_grad(self.loss.tau, self.subgradient, y_true, y_est)
+        ));
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, N, N, N> for WithSubgradient<QuantileLoss>
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for WithSubgradient<QuantileLoss>
+where
+    F: Float + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss = quantile(self.loss.tau, y_true, y_est);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+        {
+            loss_grad[i*n + i] = quantile_grad(self.loss.tau, self.subgradient, y_true, y_est);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for QuantileLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(self.tau.max(1.0 - self.tau))
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, LossFunction, Subgradient};
+    use super::QuantileLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let tau = 0.9;
+
+        t::test(LF::new(tau), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(tau), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(tau), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.5, 4.0, 5.0, 4.0]);
+        t::graph_2d(LF::new(tau), [0.3, -0.1], -1.0..1.0);
+
+        for (subgradient, l_grad) in [(Subgradient::Left, -0.9), (Subgradient::Right, 0.1), (Subgradient::Zero, 0.0), (Subgradient::Midpoint, -0.4)]
+        {
+            let [[l_grad_kink]] = LF::new(tau).with_subgradient(subgradient).lf_loss_grad([1.0], [1.0]);
+            assert!((l_grad_kink - l_grad).abs() < 1e-12, "Subgradient mismatch for {:?}: {} != {}", subgradient, l_grad_kink, l_grad);
+        }
+        let [[l_grad_kink]] = LF::new(tau).lf_loss_grad([1.0], [1.0]);
+        assert!((l_grad_kink + 0.4).abs() < 1e-12, "Default subgradient mismatch: {} != -0.4", l_grad_kink);
+    }
+}
@@ -0,0 +1,9 @@
+/// How the losses of a batch of samples are combined into one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Reduction
+{
+    #[default]
+    Mean,
+    Sum,
+    None
+}
@@ -0,0 +1,169 @@
+use std::{f64::consts::E, ops::AddAssign};
+
+use num::Float;
+use super::*;
+
+/// A differentiable surrogate of [OneZeroLoss](OneZeroLoss), `1 - exp(-(d/temperature)²)` of the distance `d` beyond `tolerance`.
+/// 
+/// The distance is `d = max(|y_est - y_true| - tolerance, 0)`, so estimates within the tolerance have zero loss,
+/// and the loss converges to [OneZeroLoss](OneZeroLoss) with the same tolerance as `temperature` goes to zero.
+#[derive(Clone, Copy, Debug)]
+pub struct SmoothOneZeroLoss
+{
+    pub tolerance: f64,
+    pub temperature: f64
+}
+
+impl SmoothOneZeroLoss
+{
+    pub fn new(tolerance: f64, temperature: f64) -> Self
+    {
+        Self {
+            tolerance,
+            temperature
+        }
+    }
+
+    fn smooth_one_zero_and_grad<F>(&self, y_true: F, y_est: F) -> (F, F)
+    where
+        F: Float
+    {
+        let one = F::one();
+        let tolerance = f!(self.tolerance; F);
+        let temperature = f!(self.temperature; F);
+
+        let e = y_est - y_true;
+        let u = (e.abs() - tolerance).max(F::zero())/temperature;
+        let exp = (-u*u).exp();
+
+        (one - exp, (u + u)/temperature*exp*e.signum())
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, Y, Y, Y> for SmoothOneZeroLoss
+where
+    F: Float + AddAssign + Default,
+    [(); Y - Y]:
+{
+    fn lf_loss(&self, y_true: [F; Y], y_est: [F; Y]) -> [F; Y]
+    {
+        y_true.comap(y_est, |y_true, y_est| self.smooth_one_zero_and_grad(y_true, y_est).0)
+    }
+    fn lf_loss_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> [[F; Y]; Y]
+    {
+        y_true.comap(y_est, |y_true, y_est| self.smooth_one_zero_and_grad(y_true, y_est).1)
+            .diagonal()
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| self.smooth_one_zero_and_grad(y_true, y_est));
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, Y, Y, Y> for SmoothOneZeroLoss
+where
+    F: Float + AddAssign + Default,
+    [(); Y - Y]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [F; Y]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; Y]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y], y_est: [F; Y], weights: [F; Y]) -> ([F; Y], [[F; Y]; Y])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for SmoothOneZeroLoss
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            *loss = self.smooth_one_zero_and_grad(y_true, y_est).0;
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+        {
+            loss_grad[i*n + i] = self.smooth_one_zero_and_grad(y_true, y_est).1;
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for SmoothOneZeroLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        false
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        // The first derivative peaks at d = temperature/sqrt(2).
+        Some((2.0/E).sqrt()/self.temperature)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(2.0/(self.temperature*self.temperature))
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, LossFunction, OneZeroLoss};
+    use super::SmoothOneZeroLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        let tolerance = 0.1;
+        let temperature = 0.5;
+
+        t::test(LF::new(tolerance, temperature), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF::new(tolerance, temperature), [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF::new(tolerance, temperature), [1.0, 2.0, 3.0, 4.0, 5.0], [1.2, 2.5, 3.7, 4.6, 4.0]);
+        t::graph_2d(LF::new(tolerance, temperature), [0.3, -0.1], -1.0..1.0);
+
+        let y_true = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_est = [1.05, 2.0, 4.0, 3.5, 4.0];
+        assert_eq!(LF::new(tolerance, 1e-3).lf_loss(y_true, y_est), OneZeroLoss.with_tolerance(tolerance).lf_loss(y_true, y_est));
+    }
+}
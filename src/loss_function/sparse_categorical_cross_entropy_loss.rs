@@ -0,0 +1,246 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Cross-entropy between the class with index `y_true[0]` and the softmax of the logits `y_est`.
+/// 
+/// Equivalent to [CategoricalCrossEntropyLoss](CategoricalCrossEntropyLoss) with a one-hot `y_true`.
+/// 
+/// # Panics
+/// 
+/// If `y_true[0]` is not the index of one of the classes in `y_est`.
+/// The [DynLossFunction](DynLossFunction) methods return [LossFunctionError::ClassIndex](LossFunctionError::ClassIndex) instead.
+#[derive(Clone, Copy, Debug)]
+pub struct SparseCategoricalCrossEntropyLoss;
+
+fn class_index<F>(y_true: F, classes: usize) -> Result<usize, LossFunctionError>
+where
+    F: Float
+{
+    if y_true.fract() != F::zero()
+    {
+        return Err(LossFunctionError::ClassIndex {classes})
+    }
+    match y_true.to_usize()
+    {
+        Some(class) if class < classes => Ok(class),
+        _ => Err(LossFunctionError::ClassIndex {classes})
+    }
+}
+
+impl<F, const Y: usize> LossFunction<F, 1, Y> for SparseCategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss(&self, [y_true]: [F; 1], y_est: [F; Y]) -> [F; 1]
+    {
+        let class = class_index(y_true, Y).unwrap();
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.map(|y_est| (y_est - max).exp()).sum().ln();
+
+        [lse - y_est[class]]
+    }
+    fn lf_loss_grad(&self, [y_true]: [F; 1], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let class = class_index(y_true, Y).unwrap();
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum_inv = exp.sum().recip();
+
+        let mut l_grad = exp.map(|exp| exp*exp_sum_inv);
+        l_grad[class] = l_grad[class] - F::one();
+
+        [l_grad]
+    }
+    fn lf_loss_and_grad(&self, [y_true]: [F; 1], y_est: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let class = class_index(y_true, Y).unwrap();
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum = exp.sum();
+        let exp_sum_inv = exp_sum.recip();
+        let lse = max + exp_sum.ln();
+
+        let mut l_grad = exp.map(|exp| exp*exp_sum_inv);
+        l_grad[class] = l_grad[class] - F::one();
+
+        ([lse - y_est[class]], [l_grad])
+    }
+}
+
+impl<F, const Y: usize> LossFunctionHessian<F, 1, Y> for SparseCategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_loss_hessian(&self, _y_true: [F; 1], y_est: [F; Y]) -> [[[F; Y]; Y]; 1]
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum_inv = exp.sum().recip();
+        let p = exp.map(|exp| exp*exp_sum_inv);
+
+        [
+            <[[F; Y]; Y]>::fill(|i| <[F; Y]>::fill(|j| if i == j
+                {
+                    p[i]*(F::one() - p[i])
+                }
+                else
+                {
+                    -p[i]*p[j]
+                }
+            ))
+        ]
+    }
+    fn lf_loss_hessian_diagonal(&self, _y_true: [F; 1], y_est: [F; Y]) -> [[F; Y]; 1]
+    {
+        let max = y_est.into_iter().fold(F::neg_infinity(), F::max);
+        let exp = y_est.map(|y_est| (y_est - max).exp());
+        let exp_sum_inv = exp.sum().recip();
+
+        [
+            exp.map(|exp| {
+                let p = exp*exp_sum_inv;
+                p*(F::one() - p)
+            })
+        ]
+    }
+}
+
+impl<F, const Y: usize> WeightedLossFunction<F, 1, Y> for SparseCategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_weighted_loss(&self, y_true: [F; 1], y_est: [F; Y], weights: [F; Y]) -> [F; 1]
+    {
+        let w = weights[class_index(y_true[0], Y).unwrap()];
+
+        self.lf_loss(y_true, y_est)
+            .map(|l| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; 1], y_est: [F; Y], weights: [F; Y]) -> [[F; Y]; 1]
+    {
+        let w = weights[class_index(y_true[0], Y).unwrap()];
+
+        self.lf_loss_grad(y_true, y_est)
+            .map(|l_grad| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; 1], y_est: [F; Y], weights: [F; Y]) -> ([F; 1], [[F; Y]; 1])
+    {
+        let w = weights[class_index(y_true[0], Y).unwrap()];
+
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.map(|l| l*w),
+            l_grad.map(|l_grad| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for SparseCategoricalCrossEntropyLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn lf_dyn_y_true_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss_len(&self, _y_est_len: usize) -> usize
+    {
+        1
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        let class = class_index(y_true[0], y_est.len())?;
+        let max = y_est.iter().copied().fold(F::neg_infinity(), F::max);
+        let lse = max + y_est.iter().fold(F::zero(), |sum, &y_est| sum + (y_est - max).exp()).ln();
+
+        loss[0] = lse - y_est[class];
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let class = class_index(y_true[0], y_est.len())?;
+        let max = y_est.iter().copied().fold(F::neg_infinity(), F::max);
+        for (loss_grad, &y_est) in loss_grad.iter_mut().zip(y_est)
+        {
+            *loss_grad = (y_est - max).exp();
+        }
+        let exp_sum_inv = loss_grad.iter().fold(F::zero(), |a, &b| a + b).recip();
+
+        for loss_grad in loss_grad.iter_mut()
+        {
+            *loss_grad = *loss_grad*exp_sum_inv;
+        }
+        loss_grad[class] = loss_grad[class] - F::one();
+        Ok(())
+    }
+}
+
+impl LossProperties for SparseCategoricalCrossEntropyLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(0.25)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, CategoricalCrossEntropyLoss, DynLossFunction, LossFunction, LossFunctionError};
+    use super::SparseCategoricalCrossEntropyLoss as LF;
+
+    #[test]
+    fn test()
+    {
+        t::test(LF, [1.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_dyn(LF, [1.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_gradient(LF, [1.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        t::test_hessian(LF, [1.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+
+        let (l, l_grad) = LF.lf_loss_and_grad([1.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        let (l_dense, l_grad_dense) = CategoricalCrossEntropyLoss.lf_loss_and_grad([0.0, 1.0, 0.0, 0.0, 0.0], [1.0, 3.0, -2.0, 0.5, 0.0]);
+        assert!((l[0] - l_dense[0]).abs() < 1e-12);
+        assert!(l_grad[0].into_iter().zip(l_grad_dense[0]).all(|(a, b)| (a - b).abs() < 1e-12));
+
+        let y_est = [1.0, 3.0, -2.0, 0.5, 0.0];
+        let mut loss = [0.0];
+        let mut loss_grad = [0.0; 5];
+        for y_true in [5.0, -1.0, 1.5, f64::NAN]
+        {
+            assert_eq!(LF.lf_dyn_loss(&[y_true], &y_est, &mut loss), Err(LossFunctionError::ClassIndex {classes: 5}));
+            assert_eq!(LF.lf_dyn_loss_grad(&[y_true], &y_est, &mut loss_grad), Err(LossFunctionError::ClassIndex {classes: 5}));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_integral_class()
+    {
+        LF.lf_loss([1.5], [1.0, 3.0, -2.0, 0.5, 0.0]);
+    }
+}
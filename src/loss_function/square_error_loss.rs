@@ -21,6 +21,115 @@ where
         y_true.comap(y_est, |y_true, y_est| (y_est - y_true)*f!(2.0))
             .diagonal()
     }
+    fn lf_loss_and_grad(&self, y_true: [F; N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let l_and_grad = y_true.comap(y_est, |y_true, y_est| {
+            let e = y_est - y_true;
+            (e*e, e*f!(2.0))
+        });
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const N: usize> LossFunctionHessian<F, N, N, N> for SquareErrorLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_loss_hessian(&self, y_true: [F; N], y_est: [F; N]) -> [[[F; N]; N]; N]
+    {
+        self.lf_loss_hessian_diagonal(y_true, y_est)
+            .map(|l_hess| l_hess.diagonal())
+    }
+    fn lf_loss_hessian_diagonal(&self, _y_true: [F; N], _y_est: [F; N]) -> [[F; N]; N]
+    {
+        [f!(2.0; F); N]
+            .diagonal()
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, N, N, N> for SquareErrorLoss
+where
+    F: Float + Default,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for SquareErrorLoss
+where
+    F: Float + Default
+{
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        for ((loss, &y_true), &y_est) in loss.iter_mut().zip(y_true).zip(y_est)
+        {
+            let e = y_est - y_true;
+            *loss = e*e;
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (&y_true, &y_est)) in y_true.iter().zip(y_est).enumerate()
+        {
+            loss_grad[i*n + i] = (y_est - y_true)*f!(2.0);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for SquareErrorLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        true
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        Some(2.0)
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -33,6 +142,9 @@ mod test
     fn test()
     {
         t::test(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_dyn(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_gradient(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
+        t::test_hessian(LF, [1.0, 2.0, 3.0, 4.0, 5.0], [1.0, 2.0, 4.0, 5.0, 4.0]);
         t::graph_2d(LF, [0.3, -0.1], -1.0..1.0);
     }
 }
\ No newline at end of file
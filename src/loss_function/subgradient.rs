@@ -0,0 +1,88 @@
+use num::Float;
+
+use super::*;
+
+/// Which subgradient a loss returns at a kink, where its derivatives from the left and right differ.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subgradient
+{
+    /// The derivative from the left.
+    Left,
+    /// The derivative from the right.
+    Right,
+    /// Zero, as if the loss were flat at the kink.
+    Zero,
+    /// The mean of the derivatives from the left and right.
+    Midpoint
+}
+
+impl Subgradient
+{
+    /// Picks the gradient from the derivatives on either side of a point, which are only different at a kink.
+    pub fn pick<F>(self, left: F, right: F) -> F
+    where
+        F: Float
+    {
+        if left == right
+        {
+            left
+        }
+        else
+        {
+            match self
+            {
+                Subgradient::Left => left,
+                Subgradient::Right => right,
+                Subgradient::Zero => F::zero(),
+                Subgradient::Midpoint => (left + right)*f!(0.5; F)
+            }
+        }
+    }
+}
+
+/// A loss with a kink, which returns the gradient picked by `subgradient` there instead of its default one.
+/// 
+/// Build it with the `with_subgradient` method of the loss.
+#[derive(Clone, Copy, Debug)]
+pub struct WithSubgradient<L>
+{
+    pub loss: L,
+    pub subgradient: Subgradient
+}
+
+impl<L> WithSubgradient<L>
+{
+    pub fn new(loss: L, subgradient: Subgradient) -> Self
+    {
+        Self {
+            loss,
+            subgradient
+        }
+    }
+}
+
+impl<L> LossProperties for WithSubgradient<L>
+where
+    L: LossProperties
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        self.loss.lf_is_convex()
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        self.loss.lf_is_differentiable()
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        self.loss.lf_lipschitz()
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        self.loss.lf_smoothness()
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        self.loss.lf_bound()
+    }
+}
@@ -3,10 +3,108 @@ use std::ops::AddAssign;
 use num::Float;
 use super::*;
 
+/// A loss of each estimate against an interval of two targets, `[y_low, y_high]`, which is flat inside the interval.
+/// 
+/// At the kinks, where `y_est == y_low` or `y_est == y_high`, the gradient is the one from the right. Use [with_subgradient](TwoParameterLoss::with_subgradient) to pick another.
 #[derive(Clone, Copy, Debug)]
 pub struct TwoParameterLoss;
 
+impl TwoParameterLoss
+{
+    pub fn with_subgradient(self, subgradient: Subgradient) -> WithSubgradient<Self>
+    {
+        WithSubgradient::new(self, subgradient)
+    }
+}
+
+impl WithSubgradient<TwoParameterLoss>
+{
+    fn two_parameter_grad<F>(&self, e_low: F, e_high: F) -> F
+    where
+        F: Float
+    {
+        let zero = F::zero();
+        let one = F::one();
+
+        let left = |e: F| if e > zero {one} else {-one};
+        let right = |e: F| if e < zero {-one} else {one};
+
+        self.subgradient.pick(
+            (left(e_low) + left(e_high))*f!(0.5),
+            (right(e_low) + right(e_high))*f!(0.5)
+        )
+    }
+}
+
 impl<F, const N: usize> LossFunction<F, {2*N}, N, N> for TwoParameterLoss
+where
+    F: Float + AddAssign + Default,
+    [(); 0 - 2*N % 2]:,
+    [(); (2*N) / 2]:,
+    [(); (2*N) / 2 - N]:,
+    [(); N - (2*N) / 2]:,
+    [(); N - N]:
+{
+    fn lf_loss(&self, y_true: [F; 2*N], y_est: [F; N]) -> [F; N]
+    {
+        self.with_subgradient(Subgradient::Right).lf_loss(y_true, y_est)
+    }
+    fn lf_loss_grad(&self, y_true: [F; 2*N], y_est: [F; N]) -> [[F; N]; N]
+    {
+        self.with_subgradient(Subgradient::Right).lf_loss_grad(y_true, y_est)
+    }
+    fn lf_loss_and_grad(&self, y_true: [F; 2*N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        self.with_subgradient(Subgradient::Right).lf_loss_and_grad(y_true, y_est)
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, {2*N}, N, N> for TwoParameterLoss
+where
+    F: Float + AddAssign + Default,
+    [(); 0 - 2*N % 2]:,
+    [(); (2*N) / 2]:,
+    [(); (2*N) / 2 - N]:,
+    [(); N - (2*N) / 2]:,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; 2*N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.with_subgradient(Subgradient::Right).lf_weighted_loss(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; 2*N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.with_subgradient(Subgradient::Right).lf_weighted_loss_grad(y_true, y_est, weights)
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; 2*N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        self.with_subgradient(Subgradient::Right).lf_weighted_loss_and_grad(y_true, y_est, weights)
+    }
+}
+
+impl<F> DynLossFunction<F> for TwoParameterLoss
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+    {
+        2*y_est_len
+    }
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Right).lf_dyn_loss(y_true, y_est, loss)
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.with_subgradient(Subgradient::Right).lf_dyn_loss_grad(y_true, y_est, loss_grad)
+    }
+}
+
+impl<F, const N: usize> LossFunction<F, {2*N}, N, N> for WithSubgradient<TwoParameterLoss>
 where
     F: Float + AddAssign + Default,
     [(); 0 - 2*N % 2]:,
@@ -25,9 +123,116 @@ where
     {
         y_true.array_chunks_exact()
             .reformulate_length()
-            .comap(y_est, |[y_low, y_high], y_est| ((y_est - y_low).signum() + (y_est - y_high).signum())*f!(0.5))
+            .comap(y_est, |[y_low, y_high], y_est| self.two_parameter_grad(y_est - y_low, y_est - y_high))
             .diagonal()
     }
+    fn lf_loss_and_grad(&self, y_true: [F; 2*N], y_est: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let l_and_grad = y_true.array_chunks_exact()
+            .reformulate_length()
+            .comap(y_est, |[y_low, y_high], y_est| {
+                let e_low = y_est - y_low;
+                let e_high = y_est - y_high;
+                (
+                    (e_low.abs() + e_high.abs() - (y_low - y_high))*f!(0.5),
+                    self.two_parameter_grad(e_low, e_high)
+                )
+            });
+
+        (l_and_grad.map(|(l, _)| l), l_and_grad.map(|(_, l_grad)| l_grad).diagonal())
+    }
+}
+
+impl<F, const N: usize> WeightedLossFunction<F, {2*N}, N, N> for WithSubgradient<TwoParameterLoss>
+where
+    F: Float + AddAssign + Default,
+    [(); 0 - 2*N % 2]:,
+    [(); (2*N) / 2]:,
+    [(); (2*N) / 2 - N]:,
+    [(); N - (2*N) / 2]:,
+    [(); N - N]:
+{
+    fn lf_weighted_loss(&self, y_true: [F; 2*N], y_est: [F; N], weights: [F; N]) -> [F; N]
+    {
+        self.lf_loss(y_true, y_est)
+            .comap(weights, |l, w| l*w)
+    }
+    fn lf_weighted_loss_grad(&self, y_true: [F; 2*N], y_est: [F; N], weights: [F; N]) -> [[F; N]; N]
+    {
+        self.lf_loss_grad(y_true, y_est)
+            .comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+    }
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; 2*N], y_est: [F; N], weights: [F; N]) -> ([F; N], [[F; N]; N])
+    {
+        let (l, l_grad) = self.lf_loss_and_grad(y_true, y_est);
+
+        (
+            l.comap(weights, |l, w| l*w),
+            l_grad.comap(weights, |l_grad, w| l_grad.map(|l_grad| l_grad*w))
+        )
+    }
+}
+
+impl<F> DynLossFunction<F> for WithSubgradient<TwoParameterLoss>
+where
+    F: Float + AddAssign + Default
+{
+    fn lf_dyn_y_true_len(&self, y_est_len: usize) -> usize
+    {
+        2*y_est_len
+    }
+    fn lf_dyn_loss_len(&self, y_est_len: usize) -> usize
+    {
+        y_est_len
+    }
+    fn lf_dyn_loss(&self, y_true: &[F], y_est: &[F], loss: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss(y_true, y_est, loss)?;
+
+        for ((loss, y_true), &y_est) in loss.iter_mut().zip(y_true.chunks_exact(2)).zip(y_est)
+        {
+            let (y_low, y_high) = (y_true[0], y_true[1]);
+            *loss = ((y_est - y_low).abs() + (y_est - y_high).abs() - (y_low - y_high))*f!(0.5);
+        }
+        Ok(())
+    }
+    fn lf_dyn_loss_grad(&self, y_true: &[F], y_est: &[F], loss_grad: &mut [F]) -> Result<(), LossFunctionError>
+    {
+        self.lf_dyn_check_loss_grad(y_true, y_est, loss_grad)?;
+
+        let n = y_est.len();
+        loss_grad.fill(F::zero());
+        for (i, (y_true, &y_est)) in y_true.chunks_exact(2).zip(y_est).enumerate()
+        {
+            let (y_low, y_high) = (y_true[0], y_true[1]);
+            loss_grad[i*n + i] = self.two_parameter_grad(y_est - y_low, y_est - y_high);
+        }
+        Ok(())
+    }
+}
+
+impl LossProperties for TwoParameterLoss
+{
+    fn lf_is_convex(&self) -> bool
+    {
+        true
+    }
+    fn lf_is_differentiable(&self) -> bool
+    {
+        false
+    }
+    fn lf_lipschitz(&self) -> Option<f64>
+    {
+        Some(1.0)
+    }
+    fn lf_smoothness(&self) -> Option<f64>
+    {
+        None
+    }
+    fn lf_bound(&self) -> Option<f64>
+    {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -35,7 +240,7 @@ mod test
 {
     use array_math::ArrayNdOps;
 
-    use crate::tests as t;
+    use crate::{tests as t, LossFunction, Subgradient};
     use super::TwoParameterLoss as LF;
 
     #[test]
@@ -45,6 +250,20 @@ mod test
             [[0.9, 1.0], [1.8, 2.0], [1.0, 3.0], [3.5, 4.0], [-1.0, 5.0]].flatten_nd_array(),
             [1.0, 2.0, 4.0, 5.0, 4.0]
         );
+        t::test_dyn(LF,
+            [[0.9, 1.0], [1.8, 2.0], [1.0, 3.0], [3.5, 4.0], [-1.0, 5.0]].flatten_nd_array(),
+            [1.0, 2.0, 4.0, 5.0, 4.0]
+        );
+        t::test_gradient(LF,
+            [[0.9, 1.0], [1.8, 2.0], [1.0, 3.0], [3.5, 4.0], [-1.0, 5.0]].flatten_nd_array(),
+            [1.2, 1.9, 4.0, 5.0, 4.0]
+        );
         t::graph_2d(LF, [[0.3, 0.4], [-0.1, 5.0]].flatten_nd_array(), -1.0..1.0);
+
+        for (subgradient, l_grad) in [(Subgradient::Left, -1.0), (Subgradient::Right, 0.0), (Subgradient::Zero, 0.0), (Subgradient::Midpoint, -0.5)]
+        {
+            assert_eq!(LF.with_subgradient(subgradient).lf_loss_grad([1.0, 3.0], [1.0]), [[l_grad]]);
+        }
+        assert_eq!(LF.lf_loss_grad([1.0, 3.0], [1.0]), [[0.0]]);
     }
 }
\ No newline at end of file
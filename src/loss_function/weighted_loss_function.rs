@@ -0,0 +1,146 @@
+use num::Float;
+
+use super::*;
+
+/// A loss function where each component of the estimate, such as each class, can be given its own weight.
+/// 
+/// The weighted terms are reduced the same way as the unweighted ones, so weights of one give back the unweighted loss.
+/// For [SparseCategoricalCrossEntropyLoss](SparseCategoricalCrossEntropyLoss), the weight of the target class is used.
+/// For the [negative log-likelihoods](likelihood::NegativeLogLikelihood), each sample uses the weight of its first parameter.
+pub trait WeightedLossFunction<F, const Y_TRUE: usize, const Y_EST: usize, const L: usize = 1>: LossFunction<F, Y_TRUE, Y_EST, L>
+{
+    fn lf_weighted_loss(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST], weights: [F; Y_EST]) -> [F; L];
+    fn lf_weighted_loss_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST], weights: [F; Y_EST]) -> [[F; Y_EST]; L];
+
+    fn lf_weighted_loss_and_grad(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST], weights: [F; Y_EST]) -> ([F; L], [[F; Y_EST]; L])
+    where
+        F: Copy
+    {
+        (self.lf_weighted_loss(y_true, y_est, weights), self.lf_weighted_loss_grad(y_true, y_est, weights))
+    }
+}
+
+/// Scales each target by the weight of its component.
+/// 
+/// For losses that are linear in the targets, such as the cross-entropies, this is the same as weighting each term of the loss.
+pub(crate) fn weight_targets<F, const Y: usize>(y_true: [F; Y], weights: [F; Y]) -> [F; Y]
+where
+    F: Float
+{
+    y_true.comap(weights, |y_true, w| y_true*w)
+}
+
+#[cfg(test)]
+mod test
+{
+    use std::ops::Range;
+
+    use array_math::ArrayNdOps;
+
+    use crate::{
+        tests as t,
+        combinator::{LossCombinators, OutputWeightedLoss, ScaledLoss},
+        likelihood::{GaussianNllLoss, LaplaceNllLoss, NegativeBinomialNllLoss, PoissonNllLoss, StudentTNllLoss},
+        multi_class::{ExponentialLoss, GeneralizedSmoothHingeLoss, HingeLoss, LogisticLoss, SavageLoss, SquareLoss, TangentLoss},
+        BinaryCrossEntropyLoss, BinaryCrossEntropyWithLogits, BinaryFocalLoss, BinaryFocalLossWithLogits,
+        CategoricalCrossEntropyLoss, SparseCategoricalCrossEntropyLoss, FocalLoss, FocalLossWithLogits,
+        SquareErrorLoss, MeanSquareError, MeanBiasError, MeanAbsoluteError, HuberLoss, MeanHuberError, PseudoHuberLoss, MeanPseudoHuberError,
+        QuantileLoss, MultiQuantileLoss, OneZeroLoss, SmoothOneZeroLoss, TwoParameterLoss
+    };
+
+    const WEIGHTS: [f64; 5] = [1.0, 0.5, 2.0, 0.0, 1.5];
+
+    const REGRESSION_TRUE: [f64; 5] = [1.0, 2.0, 3.0, 4.0, 5.0];
+    const REGRESSION_EST: [f64; 5] = [1.0, 2.0, 4.0, 5.0, 4.0];
+    const REGRESSION_EST_OFF_KINK: [f64; 5] = [1.2, 2.5, 4.0, 5.0, 4.0];
+
+    const BINARY_TRUE: [f64; 5] = [1.0, 0.0, 1.0, 0.0, 1.0];
+    const BINARY_LOGITS: [f64; 5] = [2.2, -2.2, 0.5, 0.1, 800.0];
+
+    const CLASS_TRUE: [f64; 5] = [0.0, 1.0, 0.0, 0.0, 0.0];
+    const CLASS_LOGITS: [f64; 5] = [1.0, 3.0, -2.0, 0.5, 0.0];
+
+    const MARGIN_TRUE: [f64; 5] = [1.0, -1.0, 1.0, 1.0, -1.0];
+    const MARGIN_EST: [f64; 5] = [0.6, -1.2, 0.8, 1.5, -1.3];
+
+    const COUNT_TRUE: [f64; 5] = [0.0, 2.0, 3.0, 1.0, 7.0];
+
+    const PROBABILITY_RANGE: Range<f64> = 0.0625..0.9375;
+
+    macro_rules! test_losses {
+        ($($lf:expr, $y_true:expr, $y_est:expr $(, weights: $weights:expr)? $(, properties: $y_est_range:expr)?;)*) => {
+            $(
+                $(t::test_weighted($lf, $y_true, $y_est, $weights);)?
+                $(t::test_properties($lf, $y_true, $y_est, $y_est_range);)?
+            )*
+        };
+    }
+
+    #[test]
+    fn test()
+    {
+        test_losses! {
+            SquareErrorLoss, REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            MeanSquareError, REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            MeanBiasError, REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            MeanAbsoluteError, REGRESSION_TRUE, REGRESSION_EST_OFF_KINK, weights: WEIGHTS, properties: -4.0..12.0;
+            HuberLoss::new(0.5), REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            MeanHuberError::new(0.5), REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            PseudoHuberLoss::new(0.5), REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            MeanPseudoHuberError::new(0.5), REGRESSION_TRUE, REGRESSION_EST, weights: WEIGHTS, properties: -4.0..12.0;
+            QuantileLoss::new(0.9), REGRESSION_TRUE, REGRESSION_EST_OFF_KINK, weights: WEIGHTS, properties: -4.0..12.0;
+            MultiQuantileLoss::new([0.1, 0.5, 0.9]), [2.0], [1.0, 2.5, 3.5], weights: [1.0, 0.5, 2.0], properties: -4.0..12.0;
+            OneZeroLoss, REGRESSION_TRUE, REGRESSION_EST_OFF_KINK, weights: WEIGHTS, properties: -4.0..12.0;
+            SmoothOneZeroLoss::new(0.1, 0.5), REGRESSION_TRUE, [1.2, 2.5, 3.7, 4.6, 4.0], weights: WEIGHTS, properties: -4.0..12.0;
+            TwoParameterLoss,
+                [[0.9, 1.0], [1.8, 2.0], [1.0, 3.0], [3.5, 4.0], [-1.0, 5.0]].flatten_nd_array(),
+                [1.2, 1.9, 4.0, 5.0, 4.0],
+                weights: WEIGHTS,
+                properties: -4.0..12.0;
+
+            BinaryCrossEntropyLoss, BINARY_TRUE, [0.9, 0.1, 0.9, 0.1, 0.9], weights: WEIGHTS, properties: 0.0..1e-6;
+            BinaryCrossEntropyLoss.with_epsilon(0.0), BINARY_TRUE, [0.9, 0.1, 0.9, 0.1, 0.9], properties: PROBABILITY_RANGE;
+            BinaryCrossEntropyLoss.with_epsilon(1e-3), BINARY_TRUE, [0.9, 0.1, 0.9, 0.1, 0.9], properties: 0.0..1.0;
+            BinaryCrossEntropyWithLogits, BINARY_TRUE, BINARY_LOGITS, weights: WEIGHTS, properties: -8.0..8.0;
+            BinaryFocalLoss::new(2.0, 0.25), BINARY_TRUE, [0.9, 0.1, 0.6, 0.3, 0.99], weights: WEIGHTS, properties: PROBABILITY_RANGE;
+            BinaryFocalLossWithLogits::new(2.0, 0.25), BINARY_TRUE, BINARY_LOGITS, weights: WEIGHTS, properties: -8.0..8.0;
+
+            CategoricalCrossEntropyLoss, CLASS_TRUE, CLASS_LOGITS, weights: WEIGHTS, properties: -8.0..8.0;
+            SparseCategoricalCrossEntropyLoss, [1.0], CLASS_LOGITS, weights: WEIGHTS, properties: -8.0..8.0;
+            FocalLoss::new(2.0, 1.0), CLASS_TRUE, [0.1, 0.6, 0.05, 0.15, 0.1], weights: WEIGHTS, properties: PROBABILITY_RANGE;
+            FocalLossWithLogits::new(2.0, 1.0), CLASS_TRUE, CLASS_LOGITS, weights: WEIGHTS, properties: -8.0..8.0;
+
+            ExponentialLoss, MARGIN_TRUE, MARGIN_EST, weights: WEIGHTS, properties: -8.0..8.0;
+            LogisticLoss, MARGIN_TRUE, MARGIN_EST, weights: WEIGHTS, properties: -8.0..8.0;
+            SquareLoss, MARGIN_TRUE, MARGIN_EST, weights: WEIGHTS, properties: -8.0..8.0;
+            SavageLoss, MARGIN_TRUE, MARGIN_EST, weights: WEIGHTS, properties: -8.0..8.0;
+            TangentLoss, MARGIN_TRUE, MARGIN_EST, weights: WEIGHTS, properties: -8.0..8.0;
+            HingeLoss, MARGIN_TRUE, MARGIN_EST, weights: WEIGHTS, properties: -8.0..8.0;
+            GeneralizedSmoothHingeLoss::new(0.5), MARGIN_TRUE, [0.6, -0.4, 0.8, 1.5, 0.3], weights: WEIGHTS, properties: -8.0..8.0;
+
+            GaussianNllLoss,
+                REGRESSION_TRUE,
+                [1.0, 0.0, 2.0, -0.5, 4.0, 0.5, 5.0, 0.2, 4.0, -0.3],
+                weights: [1.0, 1.0, 0.5, 0.5, 2.0, 2.0, 0.0, 0.0, 1.5, 1.5];
+            LaplaceNllLoss,
+                REGRESSION_TRUE,
+                [1.5, 0.0, 2.5, -0.5, 4.0, 0.5, 5.0, 0.2, 4.0, -0.3],
+                weights: [1.0, 1.0, 0.5, 0.5, 2.0, 2.0, 0.0, 0.0, 1.5, 1.5];
+            StudentTNllLoss,
+                REGRESSION_TRUE,
+                [1.0, 0.0, 1.0, 2.0, -0.5, 0.0, 4.0, 0.5, 2.0, 5.0, 0.2, -1.0, 4.0, -0.3, 0.5],
+                weights: [1.0, 1.0, 1.0, 0.5, 0.5, 0.5, 2.0, 2.0, 2.0, 0.0, 0.0, 0.0, 1.5, 1.5, 1.5],
+                properties: -4.0..4.0;
+            PoissonNllLoss, COUNT_TRUE, [-1.0, 0.5, 1.0, 0.2, 2.5], weights: WEIGHTS, properties: -4.0..4.0;
+            NegativeBinomialNllLoss,
+                COUNT_TRUE,
+                [-1.0, 0.0, 0.5, -0.5, 1.0, 0.5, 0.2, -2.0, 2.5, -1.0],
+                weights: [1.0, 1.0, 0.5, 0.5, 2.0, 2.0, 0.0, 0.0, 1.5, 1.5];
+
+            ScaledLoss::new(SquareErrorLoss, 0.25), REGRESSION_TRUE, REGRESSION_EST, properties: -4.0..12.0;
+            ScaledLoss::new(SquareErrorLoss, -0.25), REGRESSION_TRUE, REGRESSION_EST, properties: -4.0..12.0;
+            OutputWeightedLoss::new(SquareErrorLoss, WEIGHTS), REGRESSION_TRUE, REGRESSION_EST, properties: -4.0..12.0;
+            MeanSquareError.scaled(0.7).plus(MeanAbsoluteError.scaled(0.3)), REGRESSION_TRUE, REGRESSION_EST_OFF_KINK, properties: -4.0..12.0;
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// [R²](RSquared) adjusted for the number of features of the model, `1 - (1 - R²)*(Y - 1)/(Y - features - 1)`.
+/// 
+/// Undefined, and `NaN`, unless there are more than `features + 1` samples.
+#[derive(Clone, Copy, Debug)]
+pub struct AdjustedRSquared
+{
+    pub features: usize
+}
+
+impl AdjustedRSquared
+{
+    pub fn new(features: usize) -> Self
+    {
+        Self {
+            features
+        }
+    }
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for AdjustedRSquared
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        if Y <= self.features + 1
+        {
+            F::nan()
+        }
+        else
+        {
+            let r2 = RSquared.metric(y_true, y_est);
+            F::one() - (F::one() - r2)*f!(Y - 1; F)/f!(Y - self.features - 1; F)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let r2 = AdjustedRSquared::new(1).metric([3.0, -0.5, 2.0, 7.0], [2.5, 0.0, 2.0, 8.0]);
+        assert!((r2 - 0.9229122055674519f64).abs() < 1e-12, "Adjusted R² = {}", r2);
+
+        assert!(AdjustedRSquared::new(2).metric([3.0, -0.5, 2.0], [2.5, 0.0, 2.0]).is_nan());
+    }
+}
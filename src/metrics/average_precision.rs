@@ -0,0 +1,32 @@
+use num::Float;
+
+use super::*;
+
+/// Area under the [`PrecisionRecallCurve`], as the precisions weighted by the increase in recall at each threshold.
+#[derive(Clone, Copy, Debug)]
+pub struct AveragePrecision;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for AveragePrecision
+where
+    F: Float
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        PrecisionRecallCurve::new(y_true, y_est).average_precision()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let ap = AveragePrecision.metric([1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0], [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1]);
+        assert!((ap - 0.9166666666666666f64).abs() < 1e-12, "AP = {}", ap);
+
+        assert_eq!(AveragePrecision.metric([1.0, 0.0, 1.0], [0.9, 0.1, 0.8]), 1.0);
+    }
+}
@@ -0,0 +1,157 @@
+use std::cmp::Ordering;
+
+use num::Float;
+
+use super::*;
+
+/// Whether `y` is a positive label, which holds both for the `±1` labels used by [`multi_class`](crate::multi_class) and the `0/1` targets used by [`BinaryCrossEntropyLoss`].
+pub fn is_positive_label<F>(y: F) -> bool
+where
+    F: Float
+{
+    y > F::zero()
+}
+
+/// Counts of samples by true class (row) and predicted class (column).
+///
+/// For binary classification, class `0` is negative and class `1` is positive.
+///
+/// Ratios that would be `0/0` are zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConfusionMatrix<const C: usize>
+{
+    pub counts: [[usize; C]; C]
+}
+
+impl ConfusionMatrix<2>
+{
+    /// Confusion matrix of the binary labels `y_true` against the scores `y_est`, which predict positive where they are at or above `threshold`.
+    pub fn binary<F, const Y: usize>(y_true: [F; Y], y_est: [F; Y], threshold: f64) -> Self
+    where
+        F: Float
+    {
+        let threshold = f!(threshold; F);
+
+        let mut counts = [[0; 2]; 2];
+        for (y_true, y_est) in y_true.into_iter().zip(y_est)
+        {
+            counts[is_positive_label(y_true) as usize][(y_est >= threshold) as usize] += 1;
+        }
+
+        Self {
+            counts
+        }
+    }
+}
+
+impl<const C: usize> ConfusionMatrix<C>
+{
+    /// Confusion matrix of a batch of one-hot labels `y_true` against the class scores `y_est`, where each class is the one with the greatest score.
+    pub fn multi_class<F>(y_true: &[[F; C]], y_est: &[[F; C]]) -> Result<Self, LossFunctionError>
+    where
+        F: Float
+    {
+        LossFunctionError::check_batch_len(y_true.len(), y_est.len())?;
+
+        let argmax = |y: &[F; C]| y.iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal))
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+
+        let mut counts = [[0; C]; C];
+        for (y_true, y_est) in y_true.iter().zip(y_est)
+        {
+            counts[argmax(y_true)][argmax(y_est)] += 1;
+        }
+
+        Ok(Self {
+            counts
+        })
+    }
+
+    fn ratio(num: usize, den: usize) -> f64
+    {
+        if den == 0 {0.0} else {num as f64/den as f64}
+    }
+
+    pub fn total(&self) -> usize
+    {
+        self.counts.iter().flatten().sum()
+    }
+
+    /// Fraction of samples whose class was predicted correctly.
+    pub fn accuracy(&self) -> f64
+    {
+        Self::ratio((0..C).map(|i| self.counts[i][i]).sum(), self.total())
+    }
+
+    /// Fraction of the samples predicted as `class` that belong to it.
+    pub fn precision(&self, class: usize) -> f64
+    {
+        Self::ratio(self.counts[class][class], self.counts.iter().map(|row| row[class]).sum())
+    }
+
+    /// Fraction of the samples of `class` that were predicted as it.
+    pub fn recall(&self, class: usize) -> f64
+    {
+        Self::ratio(self.counts[class][class], self.counts[class].iter().sum())
+    }
+
+    /// Weighted harmonic mean of precision and recall of `class`, where recall is weighted `beta` times as much as precision.
+    pub fn f_beta(&self, class: usize, beta: f64) -> f64
+    {
+        let precision = self.precision(class);
+        let recall = self.recall(class);
+        let beta2 = beta*beta;
+
+        let den = beta2*precision + recall;
+        if den == 0.0 {0.0} else {(1.0 + beta2)*precision*recall/den}
+    }
+
+    /// Unweighted mean of the F-beta scores of all classes.
+    pub fn macro_f_beta(&self, beta: f64) -> f64
+    {
+        if C == 0
+        {
+            return 0.0
+        }
+        (0..C).map(|class| self.f_beta(class, beta)).sum::<f64>()/C as f64
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_est = [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1];
+
+        let cm = ConfusionMatrix::binary([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], y_est, 0.5);
+        assert_eq!(cm, ConfusionMatrix::binary([1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0], y_est, 0.5));
+        assert_eq!(cm.counts, [[3, 1], [1, 3]]);
+        assert_eq!(cm.accuracy(), 0.75);
+        assert_eq!(cm.precision(1), 0.75);
+        assert_eq!(cm.recall(1), 0.75);
+        assert_eq!(cm.f_beta(1, 1.0), 0.75);
+
+        // Scores at the threshold are positive, as for the points of the ROC and precision-recall curves
+        assert_eq!(ConfusionMatrix::binary([1.0, 0.0, 0.0], [0.5, 0.5, 0.4], 0.5).counts, [[1, 1], [0, 1]]);
+
+        let y_true = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+        let y_est = [[0.7, 0.2, 0.1], [0.1, 0.8, 0.1], [0.5, 0.2, 0.3], [0.6, 0.3, 0.1], [0.2, 0.3, 0.5]];
+
+        let cm = ConfusionMatrix::multi_class(&y_true, &y_est).unwrap();
+        assert_eq!(cm.counts, [[2, 0, 0], [0, 1, 1], [1, 0, 0]]);
+        assert_eq!(cm.accuracy(), 0.6);
+        assert_eq!(cm.precision(0), 2.0/3.0);
+        assert_eq!(cm.recall(1), 0.5);
+        assert_eq!(cm.f_beta(2, 1.0), 0.0);
+        assert!((cm.macro_f_beta(1.0) - (0.8 + 2.0/3.0)/3.0).abs() < 1e-12);
+
+        assert!(ConfusionMatrix::multi_class(&y_true, &y_est[1..]).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// The explained variance, `1 - Var(y_true - y_est)/Var(y_true)`.
+/// 
+/// Unlike [R²](RSquared), a constant bias of the estimates is not penalized.
+/// If `y_true` is constant, it is one for an estimate with no error variance and zero otherwise.
+#[derive(Clone, Copy, Debug)]
+pub struct ExplainedVariance;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for ExplainedVariance
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let len_inv = f!(Y; F).recip();
+        let y_mean = len_inv*y_true.sum();
+
+        // The variance of the error is its mean square less its squared mean
+        let [mse] = MeanSquareError.lf_loss(y_true, y_est);
+        let [mbe] = MeanBiasError.lf_loss(y_true, y_est);
+        let var_e = (mse - mbe*mbe).max(F::zero());
+        let var = len_inv*y_true.map(|y_true| (y_true - y_mean)*(y_true - y_mean)).sum();
+
+        if var == F::zero()
+        {
+            if var_e == F::zero() {F::one()} else {F::zero()}
+        }
+        else
+        {
+            F::one() - var_e/var
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [3.0, -0.5, 2.0, 7.0];
+        let y_est = [2.5, 0.0, 2.0, 8.0];
+
+        let ev = ExplainedVariance.metric(y_true, y_est);
+        assert!((ev - 0.9571734475374732f64).abs() < 1e-12, "Explained variance = {}", ev);
+
+        let ev_biased = ExplainedVariance.metric(y_true, y_est.map(|y_est| y_est + 10.0));
+        assert!((ev_biased - ev).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,53 @@
+use num::Float;
+
+use super::*;
+
+/// Weighted harmonic mean of precision and recall, where recall is weighted `beta` times as much as precision.
+///
+/// Scores at or above `threshold` predict the positive class. With `beta = 1` this is the F1 score.
+#[derive(Clone, Copy, Debug)]
+pub struct FBetaScore
+{
+    pub beta: f64,
+    pub threshold: f64
+}
+
+impl FBetaScore
+{
+    pub fn new(beta: f64, threshold: f64) -> Self
+    {
+        Self {
+            beta,
+            threshold
+        }
+    }
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for FBetaScore
+where
+    F: Float
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        f!(ConfusionMatrix::binary(y_true, y_est, self.threshold).f_beta(1, self.beta); F)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        assert_eq!(FBetaScore::new(1.0, 0.5).metric([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1]), 0.75);
+
+        // Precision 1/2, recall 1
+        let y_true = [1.0, -1.0, -1.0];
+        let y_est = [0.5, -0.2, 0.3];
+        assert!((FBetaScore::new(1.0, 0.0).metric(y_true, y_est) - 2.0/3.0f64).abs() < 1e-12);
+        assert!((FBetaScore::new(2.0, 0.0).metric(y_true, y_est) - 5.0/6.0f64).abs() < 1e-12);
+        assert!((FBetaScore::new(0.5, 0.0).metric(y_true, y_est) - 5.0/9.0f64).abs() < 1e-12);
+    }
+}
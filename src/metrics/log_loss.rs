@@ -0,0 +1,64 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// Mean binary cross-entropy of the probability estimates `y_est`, with labels mapped to `0/1` as by [`is_positive_label`].
+/// 
+/// The estimates are clamped to `[epsilon, 1 - epsilon]`, as in [`BinaryCrossEntropyLoss`].
+#[derive(Clone, Copy, Debug)]
+pub struct LogLoss
+{
+    pub epsilon: f64
+}
+
+impl LogLoss
+{
+    pub fn new(epsilon: f64) -> Self
+    {
+        Self {
+            epsilon
+        }
+    }
+}
+
+impl Default for LogLoss
+{
+    fn default() -> Self
+    {
+        Self::new(BinaryCrossEntropyLoss::DEFAULT_EPSILON)
+    }
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for LogLoss
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let y_true = y_true.map(|y_true| if is_positive_label(y_true) {F::one()} else {F::zero()});
+
+        let [l] = BinaryCrossEntropyLoss.with_epsilon(self.epsilon).lf_loss(y_true, y_est);
+        l
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_est = [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1];
+
+        let l = LogLoss::default().metric([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], y_est);
+        assert!((l - 0.4501371467932781f64).abs() < 1e-12, "Log-loss = {}", l);
+        assert_eq!(LogLoss::default().metric([1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0], y_est), l);
+
+        assert!(LogLoss::default().metric([1.0, 0.0], [0.0, 1.0]).is_finite());
+    }
+}
@@ -0,0 +1,38 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// The mean of the absolute errors relative to the targets, `|y_est - y_true|/|y_true|`, as a fraction rather than a percentage.
+/// 
+/// Targets of zero are replaced by machine epsilon, so their errors give a very large, but finite, metric.
+#[derive(Clone, Copy, Debug)]
+pub struct MeanAbsolutePercentageError;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for MeanAbsolutePercentageError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let len_inv = f!(Y; F).recip();
+
+        len_inv*y_true.comap(y_est, |y_true, y_est| (y_est - y_true).abs()/y_true.abs().max(F::epsilon()))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let mape = MeanAbsolutePercentageError.metric([3.0, -0.5, 2.0, 7.0], [2.5, 0.0, 2.0, 8.0]);
+        assert!((mape - 0.3273809523809524f64).abs() < 1e-12, "MAPE = {}", mape);
+    }
+}
@@ -0,0 +1,49 @@
+use std::cmp::Ordering;
+
+use num::Float;
+
+use super::*;
+
+/// The median of the absolute errors, which is robust to outliers.
+/// 
+/// For an even number of elements it is the mean of the two middle errors.
+#[derive(Clone, Copy, Debug)]
+pub struct MedianAbsoluteError;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for MedianAbsoluteError
+where
+    F: Float
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        if Y == 0
+        {
+            return F::nan()
+        }
+
+        let mut e = y_true.comap(y_est, |y_true, y_est| (y_est - y_true).abs());
+        e.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        if Y % 2 == 1
+        {
+            e[Y/2]
+        }
+        else
+        {
+            (e[Y/2 - 1] + e[Y/2])*f!(0.5)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        assert_eq!(MedianAbsoluteError.metric([3.0, -0.5, 2.0, 7.0], [2.5, 0.0, 2.0, 8.0]), 0.5);
+        assert_eq!(MedianAbsoluteError.metric([3.0, -0.5, 2.0, 7.0, 1.0], [2.5, 0.0, 2.0, 18.0, 1.0]), 0.5);
+    }
+}
@@ -0,0 +1,63 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// An evaluation metric of the estimates `y_est` against the targets `y_true`.
+pub trait Metric<F, const Y_TRUE: usize, const Y_EST: usize>
+{
+    fn metric(&self, y_true: [F; Y_TRUE], y_est: [F; Y_EST]) -> F;
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for MeanBiasError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let [l] = self.lf_loss(y_true, y_est);
+        l
+    }
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for MeanAbsoluteError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let [l] = self.lf_loss(y_true, y_est);
+        l
+    }
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for MeanSquareError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let [l] = self.lf_loss(y_true, y_est);
+        l
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{MeanAbsoluteError, MeanBiasError, MeanSquareError};
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_true = [3.0, -0.5, 2.0, 7.0];
+        let y_est = [2.5, 0.0, 2.0, 8.0];
+
+        assert_eq!(MeanBiasError.metric(y_true, y_est), 0.25);
+        assert_eq!(MeanAbsoluteError.metric(y_true, y_est), 0.5);
+        assert_eq!(MeanSquareError.metric(y_true, y_est), 0.375);
+    }
+}
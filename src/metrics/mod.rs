@@ -0,0 +1,26 @@
+use super::*;
+
+moddef::moddef!(
+    flat(pub) mod {
+        metric,
+
+        r_squared,
+        adjusted_r_squared,
+        explained_variance,
+        root_mean_square_error,
+        mean_absolute_percentage_error,
+        symmetric_mean_absolute_percentage_error,
+        median_absolute_error,
+
+        confusion_matrix,
+        accuracy,
+        precision,
+        recall,
+        f_beta_score,
+        roc_curve,
+        roc_auc,
+        precision_recall_curve,
+        average_precision,
+        log_loss
+    }
+);
@@ -0,0 +1,47 @@
+use num::Float;
+
+use super::*;
+
+/// Fraction of positive predictions that are positive labels.
+/// 
+/// Scores at or above `threshold` predict the positive class.
+#[derive(Clone, Copy, Debug)]
+pub struct Precision
+{
+    pub threshold: f64
+}
+
+impl Precision
+{
+    pub fn new(threshold: f64) -> Self
+    {
+        Self {
+            threshold
+        }
+    }
+}
+
+impl<F, const Y: usize> Metric<F, Y, Y> for Precision
+where
+    F: Float
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        f!(ConfusionMatrix::binary(y_true, y_est, self.threshold).precision(1); F)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_est = [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1];
+
+        assert_eq!(Precision::new(0.5).metric([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], y_est), 0.75);
+        assert_eq!(Precision::new(0.0).metric([1.0, -1.0, -1.0], [0.5, -0.2, 0.3]), 0.5);
+    }
+}
@@ -0,0 +1,69 @@
+use num::Float;
+
+use super::*;
+
+/// Precision and recall of the scores `y_est` for the binary labels `y_true`.
+///
+/// There is one point for each distinct score, in descending order, used as a threshold which predicts positive for scores at or above it.
+/// The recall is NaN if `y_true` has no positive labels.
+#[derive(Clone, Debug)]
+pub struct PrecisionRecallCurve<F>
+{
+    pub precision: Vec<F>,
+    pub recall: Vec<F>,
+    pub thresholds: Vec<F>
+}
+
+impl<F> PrecisionRecallCurve<F>
+where
+    F: Float
+{
+    pub fn new<const Y: usize>(y_true: [F; Y], y_est: [F; Y]) -> Self
+    {
+        let counts = cumulative_counts(y_true, y_est);
+        let p = counts.last()
+            .map(|&(_, tp, _)| f!(tp; F))
+            .unwrap_or(F::zero());
+
+        let mut curve = Self {
+            precision: vec![],
+            recall: vec![],
+            thresholds: vec![]
+        };
+        for (threshold, tp, fp) in counts
+        {
+            curve.precision.push(f!(tp; F)/f!(tp + fp; F));
+            curve.recall.push(f!(tp; F)/p);
+            curve.thresholds.push(threshold);
+        }
+        curve
+    }
+
+    /// Sum of the precisions weighted by the increase in recall at each threshold.
+    pub fn average_precision(&self) -> F
+    {
+        let mut recall_prev = F::zero();
+        let mut ap = F::zero();
+        for (&precision, &recall) in self.precision.iter().zip(self.recall.iter())
+        {
+            ap = ap + (recall - recall_prev)*precision;
+            recall_prev = recall;
+        }
+        ap
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let pr = PrecisionRecallCurve::new([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1]);
+        assert_eq!(pr.recall, [0.25, 0.5, 0.75, 0.75, 0.75, 1.0, 1.0, 1.0]);
+        assert_eq!(pr.precision[..4], [1.0, 1.0, 1.0, 0.75]);
+        assert!((pr.average_precision() - 0.9166666666666666f64).abs() < 1e-12);
+    }
+}
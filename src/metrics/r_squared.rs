@@ -0,0 +1,51 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// The coefficient of determination, `1 - MSE/Var(y_true)`.
+/// 
+/// If `y_true` is constant, it is one for a perfect estimate and zero otherwise.
+#[derive(Clone, Copy, Debug)]
+pub struct RSquared;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for RSquared
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let len_inv = f!(Y; F).recip();
+        let y_mean = len_inv*y_true.sum();
+
+        let [mse] = MeanSquareError.lf_loss(y_true, y_est);
+        let var = len_inv*y_true.map(|y_true| (y_true - y_mean)*(y_true - y_mean)).sum();
+
+        if var == F::zero()
+        {
+            if mse == F::zero() {F::one()} else {F::zero()}
+        }
+        else
+        {
+            F::one() - mse/var
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let r2 = RSquared.metric([3.0, -0.5, 2.0, 7.0], [2.5, 0.0, 2.0, 8.0]);
+        assert!((r2 - 0.9486081370449679f64).abs() < 1e-12, "R² = {}", r2);
+
+        assert_eq!(RSquared.metric([1.0, 1.0], [1.0, 1.0]), 1.0);
+        assert_eq!(RSquared.metric([1.0, 1.0], [1.0, 2.0]), 0.0);
+    }
+}
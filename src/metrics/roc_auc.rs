@@ -0,0 +1,35 @@
+use num::Float;
+
+use super::*;
+
+/// Area under the [`RocCurve`], which is the probability that a random positive label scores above a random negative one.
+/// 
+/// Ties count as half.
+#[derive(Clone, Copy, Debug)]
+pub struct RocAuc;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for RocAuc
+where
+    F: Float
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        RocCurve::new(y_true, y_est).auc()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_est = [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1];
+
+        assert_eq!(RocAuc.metric([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], y_est), 0.875);
+        assert_eq!(RocAuc.metric([1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0], y_est), 0.875);
+        assert_eq!(RocAuc.metric([1.0, -1.0], [-1.0, 1.0]), 0.0);
+    }
+}
@@ -0,0 +1,99 @@
+use std::cmp::Ordering;
+
+use num::Float;
+
+use super::*;
+
+/// The distinct scores in descending order, each with the cumulative counts of positive and negative labels scoring at least that much.
+pub(crate) fn cumulative_counts<F, const Y: usize>(y_true: [F; Y], y_est: [F; Y]) -> Vec<(F, usize, usize)>
+where
+    F: Float
+{
+    let mut samples = y_true.comap(y_est, |y_true, y_est| (is_positive_label(y_true), y_est));
+    samples.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+
+    let mut counts: Vec<(F, usize, usize)> = vec![];
+    let (mut tp, mut fp) = (0, 0);
+    for (i, &(positive, y_est)) in samples.iter().enumerate()
+    {
+        if positive {tp += 1} else {fp += 1}
+        if samples.get(i + 1).map_or(true, |&(_, next)| next != y_est)
+        {
+            counts.push((y_est, tp, fp))
+        }
+    }
+    counts
+}
+
+/// Receiver operating characteristic of the scores `y_est` for the binary labels `y_true`.
+///
+/// There is one point for each distinct score, used as a threshold which predicts positive for scores at or above it,
+/// starting from the point `(0, 0)` at an infinite threshold.
+/// The rates are NaN if `y_true` lacks either class.
+#[derive(Clone, Debug)]
+pub struct RocCurve<F>
+{
+    pub false_positive_rate: Vec<F>,
+    pub true_positive_rate: Vec<F>,
+    pub thresholds: Vec<F>
+}
+
+impl<F> RocCurve<F>
+where
+    F: Float
+{
+    pub fn new<const Y: usize>(y_true: [F; Y], y_est: [F; Y]) -> Self
+    {
+        let counts = cumulative_counts(y_true, y_est);
+        let (p, n) = counts.last()
+            .map(|&(_, tp, fp)| (f!(tp; F), f!(fp; F)))
+            .unwrap_or((F::zero(), F::zero()));
+
+        let mut curve = Self {
+            false_positive_rate: vec![F::zero()],
+            true_positive_rate: vec![F::zero()],
+            thresholds: vec![F::infinity()]
+        };
+        for (threshold, tp, fp) in counts
+        {
+            curve.false_positive_rate.push(f!(fp; F)/n);
+            curve.true_positive_rate.push(f!(tp; F)/p);
+            curve.thresholds.push(threshold);
+        }
+        curve
+    }
+
+    /// Area under the curve by the trapezoidal rule.
+    pub fn auc(&self) -> F
+    {
+        self.false_positive_rate.windows(2)
+            .zip(self.true_positive_rate.windows(2))
+            .map(|(fpr, tpr)| (fpr[1] - fpr[0])*(tpr[0] + tpr[1])*f!(0.5))
+            .fold(F::zero(), |a, b| a + b)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let y_est = [0.9, 0.4, 0.65, 0.3, 0.2, 0.55, 0.8, 0.1];
+
+        let roc = RocCurve::new([1.0, 0.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0], y_est);
+        assert_eq!(roc.false_positive_rate, [0.0, 0.0, 0.0, 0.0, 0.25, 0.5, 0.5, 0.75, 1.0]);
+        assert_eq!(roc.true_positive_rate, [0.0, 0.25, 0.5, 0.75, 0.75, 0.75, 1.0, 1.0, 1.0]);
+        assert_eq!(roc.thresholds[1..], [0.9, 0.8, 0.65, 0.55, 0.4, 0.3, 0.2, 0.1]);
+        assert_eq!(roc.auc(), 0.875);
+
+        assert_eq!(RocCurve::new([1.0, -1.0, 1.0, 1.0, -1.0, -1.0, 1.0, -1.0], y_est).auc(), 0.875);
+
+        // Tied scores form a single point
+        let roc = RocCurve::new([1.0, 0.0, 1.0, 0.0], [0.5, 0.5, 0.8, 0.2]);
+        assert_eq!(roc.thresholds.len(), 4);
+        assert_eq!(roc.auc(), 0.875);
+    }
+}
@@ -0,0 +1,34 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// The square root of the [mean square error](MeanSquareError).
+#[derive(Clone, Copy, Debug)]
+pub struct RootMeanSquareError;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for RootMeanSquareError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let [mse] = MeanSquareError.lf_loss(y_true, y_est);
+        mse.sqrt()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let rmse = RootMeanSquareError.metric([3.0, -0.5, 2.0, 7.0], [2.5, 0.0, 2.0, 8.0]);
+        assert!((rmse - 0.6123724356957945f64).abs() < 1e-12, "RMSE = {}", rmse);
+    }
+}
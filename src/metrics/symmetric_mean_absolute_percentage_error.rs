@@ -0,0 +1,49 @@
+use std::ops::AddAssign;
+
+use num::Float;
+use num_identities_const::ZeroConst;
+
+use super::*;
+
+/// The mean of the absolute errors relative to the mean magnitude of target and estimate, `2|y_est - y_true|/(|y_true| + |y_est|)`.
+/// 
+/// Ranges from zero to two. Elements where both the target and the estimate are zero count as zero error.
+#[derive(Clone, Copy, Debug)]
+pub struct SymmetricMeanAbsolutePercentageError;
+
+impl<F, const Y: usize> Metric<F, Y, Y> for SymmetricMeanAbsolutePercentageError
+where
+    F: Float + AddAssign + ZeroConst
+{
+    fn metric(&self, y_true: [F; Y], y_est: [F; Y]) -> F
+    {
+        let len_inv = f!(Y; F).recip();
+
+        len_inv*y_true.comap(y_est, |y_true, y_est| {
+            let magnitude = y_true.abs() + y_est.abs();
+            if magnitude == F::zero()
+            {
+                F::zero()
+            }
+            else
+            {
+                f!(2.0; F)*(y_est - y_true).abs()/magnitude
+            }
+        }).sum()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let smape = SymmetricMeanAbsolutePercentageError.metric([3.0, -0.5, 2.0, 7.0], [2.5, 0.0, 2.0, 8.0]);
+        assert!((smape - 0.5787878787878787f64).abs() < 1e-12, "sMAPE = {}", smape);
+
+        assert_eq!(SymmetricMeanAbsolutePercentageError.metric([0.0, 1.0], [0.0, -1.0]), 1.0);
+    }
+}
@@ -0,0 +1,45 @@
+use num::Float;
+
+use super::*;
+
+/// The affine function `weights·x + bias`, shared by the linear models.
+pub(crate) fn affine<F, const X: usize>(weights: [F; X], bias: F, x: [F; X]) -> F
+where
+    F: Float
+{
+    x.comap(weights, |x, w| w*x)
+        .into_iter()
+        .fold(bias, |y, wx| y + wx)
+}
+
+/// Trains the affine model `y_est = weights·x + bias` on the samples `x` by `epochs` full-batch steps of `opt` against the gradient of `lf`.
+/// 
+/// The parameters are the weights followed by the bias.
+pub(crate) fn fit_linear<F, LF, O, const X: usize, const N: usize, const L: usize>(
+    weights: &mut [F; X],
+    bias: &mut F,
+    lf: &LF,
+    opt: &mut O,
+    x: [[F; X]; N],
+    y_true: [F; N],
+    epochs: usize
+)
+where
+    F: Float,
+    LF: LossFunction<F, N, N, L>,
+    O: optim::Optimizer<F, {X + 1}>,
+    [(); X + 1]:
+{
+    let jacobian = x.map(|x| <[F; X + 1]>::fill(|i| if i < X {x[i]} else {F::one()}));
+
+    let mut params = <[F; X + 1]>::fill(|i| if i < X {weights[i]} else {*bias});
+    for _ in 0..epochs
+    {
+        let weights = <[F; X]>::fill(|i| params[i]);
+        let y_est = x.map(|x| affine(weights, params[X], x));
+        opt.opt_step_loss_grad(&mut params, lf.lf_loss_grad(y_true, y_est), jacobian);
+    }
+
+    *weights = <[F; X]>::fill(|i| params[i]);
+    *bias = params[X];
+}
@@ -0,0 +1,125 @@
+use num::Float;
+
+use super::*;
+use crate::multi_class::ProperCompositeLoss;
+
+/// A linear binary classifier of `X` features, with margin `weights·x + bias` and labels `1` or `-1`.
+/// 
+/// It is meant to be trained with one of the margin losses in [`multi_class`](crate::multi_class), which apply to each sample.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearClassifier<F, const X: usize>
+{
+    pub weights: [F; X],
+    pub bias: F
+}
+
+impl<F, const X: usize> LinearClassifier<F, X>
+where
+    F: Float
+{
+    pub fn new(weights: [F; X], bias: F) -> Self
+    {
+        Self {
+            weights,
+            bias
+        }
+    }
+
+    /// The margin `weights·x + bias`, whose sign is the predicted label.
+    pub fn decision_function(&self, x: [F; X]) -> F
+    {
+        affine(self.weights, self.bias, x)
+    }
+
+    pub fn predict(&self, x: [F; X]) -> F
+    {
+        if self.decision_function(x) >= F::zero() {F::one()} else {-F::one()}
+    }
+
+    pub fn predict_batch<const N: usize>(&self, x: [[F; X]; N]) -> [F; N]
+    {
+        x.map(|x| self.predict(x))
+    }
+
+    /// The probability of the positive label, by the inverse link of the proper composite loss `lf` the model was trained with.
+    pub fn predict_proba<LF>(&self, lf: &LF, x: [F; X]) -> F
+    where
+        LF: ProperCompositeLoss
+    {
+        lf.inverse_link(self.decision_function(x))
+    }
+
+    /// Trains the model on the samples `x` with labels `y_true`, by `epochs` full-batch steps of `opt` against the gradient of `lf`.
+    pub fn fit<LF, O, const N: usize, const L: usize>(&mut self, lf: &LF, opt: &mut O, x: [[F; X]; N], y_true: [F; N], epochs: usize)
+    where
+        LF: LossFunction<F, N, N, L>,
+        O: optim::Optimizer<F, {X + 1}>,
+        [(); X + 1]:
+    {
+        fit_linear(&mut self.weights, &mut self.bias, lf, opt, x, y_true, epochs)
+    }
+}
+
+impl<F, const X: usize> Default for LinearClassifier<F, X>
+where
+    F: Float
+{
+    fn default() -> Self
+    {
+        Self::new([F::zero(); X], F::zero())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{multi_class::{HingeLoss, LogisticLoss, SavageLoss, TangentLoss}, optim::Sgd, LossFunction};
+
+    use super::*;
+
+    const X: [[f64; 2]; 8] = [[2.0, 1.0], [1.0, 2.0], [3.0, 0.5], [2.0, 2.0], [0.0, 0.0], [0.5, -0.5], [-1.0, 0.5], [0.0, -1.0]];
+    const Y_TRUE: [f64; 8] = [1.0, 1.0, 1.0, 1.0, -1.0, -1.0, -1.0, -1.0];
+
+    fn fit<LF>(lf: LF) -> LinearClassifier<f64, 2>
+    where
+        LF: LossFunction<f64, 8, 8, 8>
+    {
+        // The losses are summed over the samples, so their curvature adds up. With the tangent loss's curvature of up to 8.5 per sample,
+        // a step of 0.1 overshoots and oscillates, while 0.01 descends on every epoch.
+        const LEARNING_RATE: f64 = 0.01;
+        const EPOCHS: usize = 500;
+
+        let loss = |model: &LinearClassifier<f64, 2>| lf.lf_loss(Y_TRUE, X.map(|x| model.decision_function(x))).into_iter().sum::<f64>();
+
+        let mut model = LinearClassifier::default();
+        let mut opt = Sgd::new(LEARNING_RATE, 0.0, false);
+        let loss_initial = loss(&model);
+        let mut loss_prev = loss_initial;
+        for _ in 0..EPOCHS
+        {
+            model.fit(&lf, &mut opt, X, Y_TRUE, 1);
+            let loss_next = loss(&model);
+            assert!(loss_next <= loss_prev + 1e-12, "{} increased from {} to {}", core::any::type_name::<LF>(), loss_prev, loss_next);
+            loss_prev = loss_next;
+        }
+        println!("{} fit = {:?}, loss = {}", core::any::type_name::<LF>(), model, loss_prev);
+        assert!(loss_prev < loss_initial);
+        assert_eq!(model.predict_batch(X), Y_TRUE);
+        model
+    }
+
+    #[test]
+    fn test()
+    {
+        fit(HingeLoss);
+        fit(TangentLoss);
+        fit(SavageLoss);
+
+        let model = fit(LogisticLoss);
+        for (x, y_true) in X.into_iter().zip(Y_TRUE)
+        {
+            let p = model.predict_proba(&LogisticLoss, x);
+            assert!((p > 0.5) == (y_true > 0.0), "P(y = 1 | {:?}) = {}", x, p);
+        }
+    }
+}
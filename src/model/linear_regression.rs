@@ -0,0 +1,79 @@
+use num::Float;
+
+use super::*;
+
+/// A linear regression model `y_est = weights·x + bias` of `X` features.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearRegression<F, const X: usize>
+{
+    pub weights: [F; X],
+    pub bias: F
+}
+
+impl<F, const X: usize> LinearRegression<F, X>
+where
+    F: Float
+{
+    pub fn new(weights: [F; X], bias: F) -> Self
+    {
+        Self {
+            weights,
+            bias
+        }
+    }
+
+    pub fn predict(&self, x: [F; X]) -> F
+    {
+        affine(self.weights, self.bias, x)
+    }
+
+    pub fn predict_batch<const N: usize>(&self, x: [[F; X]; N]) -> [F; N]
+    {
+        x.map(|x| self.predict(x))
+    }
+
+    /// Trains the model on the samples `x` with targets `y_true`, by `epochs` full-batch steps of `opt` against the gradient of `lf`.
+    pub fn fit<LF, O, const N: usize, const L: usize>(&mut self, lf: &LF, opt: &mut O, x: [[F; X]; N], y_true: [F; N], epochs: usize)
+    where
+        LF: LossFunction<F, N, N, L>,
+        O: optim::Optimizer<F, {X + 1}>,
+        [(); X + 1]:
+    {
+        fit_linear(&mut self.weights, &mut self.bias, lf, opt, x, y_true, epochs)
+    }
+}
+
+impl<F, const X: usize> Default for LinearRegression<F, X>
+where
+    F: Float
+{
+    fn default() -> Self
+    {
+        Self::new([F::zero(); X], F::zero())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{optim::{Adam, Sgd}, MeanAbsoluteError, MeanSquareError};
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let x = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [1.0, 2.0]];
+        let y_true = x.map(|[x0, x1]| 2.0*x0 - x1 + 0.5);
+
+        let mut model = LinearRegression::default();
+        model.fit(&MeanSquareError, &mut Sgd::new(0.05, 0.9, true), x, y_true, 500);
+        println!("MSE fit = {:?}", model);
+        assert!(model.predict_batch(x).comap(y_true, |y_est, y_true| (y_est - y_true).abs()).into_iter().all(|e| e < 1e-6));
+
+        let mut model = LinearRegression::default();
+        model.fit(&MeanAbsoluteError, &mut Adam::new(0.01), x, y_true, 2000);
+        println!("MAE fit = {:?}", model);
+        assert!(model.predict_batch(x).comap(y_true, |y_est, y_true| (y_est - y_true).abs()).into_iter().all(|e| e < 0.1));
+    }
+}
@@ -0,0 +1,10 @@
+use super::*;
+
+moddef::moddef!(
+    flat(pub) mod {
+        linear,
+
+        linear_regression,
+        linear_classifier
+    }
+);
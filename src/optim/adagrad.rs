@@ -0,0 +1,59 @@
+use num::Float;
+
+use super::*;
+
+/// Adaptive gradient descent, which scales each step by the root of the sum of all squared gradients so far.
+#[derive(Clone, Copy, Debug)]
+pub struct Adagrad<F, const P: usize>
+{
+    pub learning_rate: f64,
+    pub epsilon: f64,
+    square_sum: [F; P]
+}
+
+impl<F, const P: usize> Adagrad<F, P>
+where
+    F: Float
+{
+    pub const DEFAULT_EPSILON: f64 = 1e-10;
+
+    pub fn new(learning_rate: f64) -> Self
+    {
+        Self {
+            learning_rate,
+            epsilon: Self::DEFAULT_EPSILON,
+            square_sum: [F::zero(); P]
+        }
+    }
+}
+
+impl<F, const P: usize> Optimizer<F, P> for Adagrad<F, P>
+where
+    F: Float
+{
+    fn opt_step(&mut self, params: &mut [F; P], grad: [F; P])
+    {
+        let learning_rate = f!(self.learning_rate; F);
+        let epsilon = f!(self.epsilon; F);
+
+        for ((param, square_sum), grad) in params.iter_mut().zip(self.square_sum.iter_mut()).zip(grad)
+        {
+            *square_sum = *square_sum + grad*grad;
+            *param = *param - learning_rate*grad/(square_sum.sqrt() + epsilon)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        t::test_optimizer(Adagrad::new(0.5), 2000);
+    }
+}
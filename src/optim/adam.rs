@@ -0,0 +1,77 @@
+use num::Float;
+
+use super::*;
+
+/// Adaptive moment estimation, which scales each step by bias-corrected running averages of the gradient and its square.
+#[derive(Clone, Copy, Debug)]
+pub struct Adam<F, const P: usize>
+{
+    pub learning_rate: f64,
+    pub betas: (f64, f64),
+    pub epsilon: f64,
+    m: [F; P],
+    v: [F; P],
+    t: i32
+}
+
+impl<F, const P: usize> Adam<F, P>
+where
+    F: Float
+{
+    pub const DEFAULT_BETAS: (f64, f64) = (0.9, 0.999);
+    pub const DEFAULT_EPSILON: f64 = 1e-8;
+
+    pub fn new(learning_rate: f64) -> Self
+    {
+        Self {
+            learning_rate,
+            betas: Self::DEFAULT_BETAS,
+            epsilon: Self::DEFAULT_EPSILON,
+            m: [F::zero(); P],
+            v: [F::zero(); P],
+            t: 0
+        }
+    }
+}
+
+impl<F, const P: usize> Optimizer<F, P> for Adam<F, P>
+where
+    F: Float
+{
+    fn opt_step(&mut self, params: &mut [F; P], grad: [F; P])
+    {
+        let learning_rate = f!(self.learning_rate; F);
+        let (beta1, beta2) = (f!(self.betas.0; F), f!(self.betas.1; F));
+        let epsilon = f!(self.epsilon; F);
+
+        self.t += 1;
+        let m_correction = (F::one() - beta1.powi(self.t)).recip();
+        let v_correction = (F::one() - beta2.powi(self.t)).recip();
+
+        for (((param, m), v), grad) in params.iter_mut().zip(self.m.iter_mut()).zip(self.v.iter_mut()).zip(grad)
+        {
+            *m = beta1**m + (F::one() - beta1)*grad;
+            *v = beta2**v + (F::one() - beta2)*grad*grad;
+            *param = *param - learning_rate*(*m*m_correction)/((*v*v_correction).sqrt() + epsilon)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        t::test_optimizer(Adam::new(0.05), 2000);
+
+        // The first step has the size of the learning rate, whatever the scale of the gradient
+        let mut params = [0.0, 0.0];
+        Adam::new(0.1).opt_step(&mut params, [1e-3, -1e3]);
+        assert!((params[0] + 0.1).abs() < 1e-5 && (params[1] - 0.1).abs() < 1e-5, "{:?}", params);
+    }
+}
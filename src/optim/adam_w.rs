@@ -0,0 +1,86 @@
+use num::Float;
+
+use super::*;
+
+/// [`Adam`] with decoupled weight decay, which shrinks the parameters directly rather than through the gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct AdamW<F, const P: usize>
+{
+    pub adam: Adam<F, P>,
+    pub weight_decay: f64
+}
+
+impl<F, const P: usize> AdamW<F, P>
+where
+    F: Float
+{
+    pub const DEFAULT_WEIGHT_DECAY: f64 = 0.01;
+
+    pub fn new(learning_rate: f64) -> Self
+    {
+        Self {
+            adam: Adam::new(learning_rate),
+            weight_decay: Self::DEFAULT_WEIGHT_DECAY
+        }
+    }
+}
+
+impl<F, const P: usize> Optimizer<F, P> for AdamW<F, P>
+where
+    F: Float
+{
+    fn opt_step(&mut self, params: &mut [F; P], grad: [F; P])
+    {
+        let decay = F::one() - f!(self.adam.learning_rate*self.weight_decay; F);
+
+        for param in params.iter_mut()
+        {
+            *param = *param*decay
+        }
+        self.adam.opt_step(params, grad)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{tests as t, LossFunction, MeanSquareError};
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        t::test_optimizer(AdamW {weight_decay: 0.0, ..AdamW::new(0.05)}, 2000);
+
+        // Without a gradient, the parameters only decay
+        let mut params = [2.0];
+        AdamW {weight_decay: 0.5, ..AdamW::new(0.1)}.opt_step(&mut params, [0.0]);
+        assert!((params[0] - 2.0*0.95f64).abs() < 1e-12, "{:?}", params);
+
+        // With the default decay, every step shrinks the parameters by a factor 1 - 0.05*0.01, which the fit keeps undoing,
+        // so after 2000 steps the bias still lags about 1.7e-2 behind 1 while the mean square error is about 1e-4.
+        // A parameter the loss doesn't depend on only shrinks, geometrically.
+        const STEPS: i32 = 2000;
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_true = x.map(|x| 2.0*x + 1.0);
+        let jacobian = x.map(|x| [x, 1.0, 0.0]);
+
+        let mut opt = AdamW::new(0.05);
+        let mut params = [0.0, 0.0, 1.0];
+        for _ in 0..STEPS
+        {
+            let [w, b, _] = params;
+            let y_est = x.map(|x| w*x + b);
+            opt.opt_step_loss_grad(&mut params, MeanSquareError.lf_loss_grad(y_true, y_est), jacobian);
+        }
+
+        let [w, b, _] = params;
+        let [loss] = MeanSquareError.lf_loss(y_true, x.map(|x| w*x + b));
+        assert!(loss < 1e-3, "Linear fit did not converge: {:?}, loss = {}", params, loss);
+        assert!((params[0] - 2.0).abs() < 5e-2 && (params[1] - 1.0).abs() < 5e-2, "Linear fit did not converge: {:?}", params);
+
+        let shrinkage = (1.0 - 0.05*AdamW::<f64, 3>::DEFAULT_WEIGHT_DECAY).powi(STEPS);
+        assert!((params[2] - shrinkage).abs() < 1e-9, "{} != {}", params[2], shrinkage);
+    }
+}
@@ -0,0 +1,13 @@
+use super::*;
+
+moddef::moddef!(
+    flat(pub) mod {
+        optimizer,
+
+        sgd,
+        adam,
+        adam_w,
+        rms_prop,
+        adagrad
+    }
+);
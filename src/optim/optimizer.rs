@@ -0,0 +1,53 @@
+use num::Float;
+
+use super::*;
+
+/// Chains the loss gradient `l_grad` with respect to the estimates through the `jacobian` of the estimates with respect to `P` parameters.
+/// 
+/// The result is the gradient of the sum of the `L` losses with respect to the parameters.
+pub fn chain_loss_grad<F, const Y_EST: usize, const L: usize, const P: usize>(l_grad: [[F; Y_EST]; L], jacobian: [[F; P]; Y_EST]) -> [F; P]
+where
+    F: Float
+{
+    let mut grad = [F::zero(); P];
+    for l_grad in l_grad
+    {
+        for (l_grad, jacobian) in l_grad.into_iter().zip(jacobian)
+        {
+            for (grad, jacobian) in grad.iter_mut().zip(jacobian)
+            {
+                *grad = *grad + l_grad*jacobian
+            }
+        }
+    }
+    grad
+}
+
+/// A gradient-descent optimizer of `P` parameters.
+pub trait Optimizer<F, const P: usize>
+{
+    /// Updates `params` by one step against the gradient `grad` of the objective.
+    fn opt_step(&mut self, params: &mut [F; P], grad: [F; P]);
+
+    /// Updates `params` by one step against the loss gradient `l_grad`, as given by [`lf_loss_grad`](LossFunction::lf_loss_grad),
+    /// chained through the `jacobian` of the estimates with respect to the parameters.
+    fn opt_step_loss_grad<const Y_EST: usize, const L: usize>(&mut self, params: &mut [F; P], l_grad: [[F; Y_EST]; L], jacobian: [[F; P]; Y_EST])
+    where
+        F: Float
+    {
+        self.opt_step(params, chain_loss_grad(l_grad, jacobian))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let grad = chain_loss_grad([[1.0, 2.0], [0.5, -1.0]], [[1.0, 0.0, 2.0], [3.0, 1.0, 0.0]]);
+        assert_eq!(grad, [1.5*1.0 + 1.0*3.0, 1.0, 3.0]);
+    }
+}
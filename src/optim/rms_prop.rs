@@ -0,0 +1,63 @@
+use num::Float;
+
+use super::*;
+
+/// Root mean square propagation, which scales each step by a running average of the squared gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct RmsProp<F, const P: usize>
+{
+    pub learning_rate: f64,
+    pub alpha: f64,
+    pub epsilon: f64,
+    square_avg: [F; P]
+}
+
+impl<F, const P: usize> RmsProp<F, P>
+where
+    F: Float
+{
+    pub const DEFAULT_ALPHA: f64 = 0.99;
+    pub const DEFAULT_EPSILON: f64 = 1e-8;
+
+    pub fn new(learning_rate: f64) -> Self
+    {
+        Self {
+            learning_rate,
+            alpha: Self::DEFAULT_ALPHA,
+            epsilon: Self::DEFAULT_EPSILON,
+            square_avg: [F::zero(); P]
+        }
+    }
+}
+
+impl<F, const P: usize> Optimizer<F, P> for RmsProp<F, P>
+where
+    F: Float
+{
+    fn opt_step(&mut self, params: &mut [F; P], grad: [F; P])
+    {
+        let learning_rate = f!(self.learning_rate; F);
+        let alpha = f!(self.alpha; F);
+        let epsilon = f!(self.epsilon; F);
+
+        for ((param, square_avg), grad) in params.iter_mut().zip(self.square_avg.iter_mut()).zip(grad)
+        {
+            *square_avg = alpha**square_avg + (F::one() - alpha)*grad*grad;
+            *param = *param - learning_rate*grad/(square_avg.sqrt() + epsilon)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        t::test_optimizer(RmsProp::new(0.002), 2000);
+    }
+}
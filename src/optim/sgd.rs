@@ -0,0 +1,70 @@
+use num::Float;
+
+use super::*;
+
+/// Stochastic gradient descent with optional momentum.
+/// 
+/// With `nesterov`, the step looks ahead along the velocity, as in Nesterov's accelerated gradient.
+#[derive(Clone, Copy, Debug)]
+pub struct Sgd<F, const P: usize>
+{
+    pub learning_rate: f64,
+    pub momentum: f64,
+    pub nesterov: bool,
+    velocity: [F; P]
+}
+
+impl<F, const P: usize> Sgd<F, P>
+where
+    F: Float
+{
+    pub fn new(learning_rate: f64, momentum: f64, nesterov: bool) -> Self
+    {
+        Self {
+            learning_rate,
+            momentum,
+            nesterov,
+            velocity: [F::zero(); P]
+        }
+    }
+}
+
+impl<F, const P: usize> Optimizer<F, P> for Sgd<F, P>
+where
+    F: Float
+{
+    fn opt_step(&mut self, params: &mut [F; P], grad: [F; P])
+    {
+        let learning_rate = f!(self.learning_rate; F);
+        let momentum = f!(self.momentum; F);
+
+        for ((param, velocity), grad) in params.iter_mut().zip(self.velocity.iter_mut()).zip(grad)
+        {
+            *velocity = momentum**velocity + grad;
+            let step = if self.nesterov {grad + momentum**velocity} else {*velocity};
+            *param = *param - learning_rate*step
+        }
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        t::test_optimizer(Sgd::new(0.05, 0.0, false), 2000);
+        t::test_optimizer(Sgd::new(0.02, 0.9, false), 1000);
+        t::test_optimizer(Sgd::new(0.02, 0.9, true), 1000);
+
+        let mut params = [1.0];
+        let mut sgd = Sgd::new(0.5, 0.5, false);
+        sgd.opt_step(&mut params, [1.0]);
+        sgd.opt_step(&mut params, [1.0]);
+        assert_eq!(params, [1.0 - 0.5 - 0.75]);
+    }
+}
@@ -0,0 +1,73 @@
+use num::Float;
+
+use super::*;
+
+/// A mix of the [`L1`] and [`L2`] penalties, `strength*(l1_ratio*Σ|params| + (1 - l1_ratio)*Σparams²/2)`.
+/// 
+/// This keeps the sparsity of the lasso while sharing weight between correlated parameters like the ridge.
+#[derive(Clone, Copy, Debug)]
+pub struct ElasticNet
+{
+    pub strength: f64,
+    pub l1_ratio: f64
+}
+
+impl ElasticNet
+{
+    pub fn new(strength: f64, l1_ratio: f64) -> Self
+    {
+        Self {
+            strength,
+            l1_ratio
+        }
+    }
+
+    fn l1(&self) -> L1
+    {
+        L1::new(self.strength*self.l1_ratio)
+    }
+    fn l2(&self) -> L2
+    {
+        L2::new(self.strength*(1.0 - self.l1_ratio))
+    }
+}
+
+impl Regularizer for ElasticNet
+{
+    fn reg_penalty<F, const P: usize>(&self, params: [F; P]) -> F
+    where
+        F: Float
+    {
+        self.l1().reg_penalty(params) + self.l2().reg_penalty(params)
+    }
+    fn reg_penalty_grad<F, const P: usize>(&self, params: [F; P]) -> [F; P]
+    where
+        F: Float
+    {
+        self.l2().reg_add_grad(params, self.l1().reg_penalty_grad(params))
+    }
+    fn reg_prox<F, const P: usize>(&self, params: [F; P], step: F) -> [F; P]
+    where
+        F: Float
+    {
+        self.l2().reg_prox(self.l1().reg_prox(params, step), step)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        t::test_regularizer(ElasticNet::new(1.0, 0.5), [2.2, 0.0, 0.6]);
+
+        // The extremes are the lasso and the ridge
+        t::test_regularizer(ElasticNet::new(1.0, 1.0), [2.5, 0.0, 0.5]);
+        t::test_regularizer(ElasticNet::new(1.0, 0.0), [2.0, -0.2/1.5, 1.0/1.5]);
+    }
+}
@@ -0,0 +1,66 @@
+use num::Float;
+
+use super::*;
+
+/// The lasso penalty `strength*Σ|params|`, which drives small parameters to exactly zero.
+/// 
+/// Its subgradient is zero where a parameter is zero, and its proximal operator is soft-thresholding.
+#[derive(Clone, Copy, Debug)]
+pub struct L1
+{
+    pub strength: f64
+}
+
+impl L1
+{
+    pub fn new(strength: f64) -> Self
+    {
+        Self {
+            strength
+        }
+    }
+}
+
+impl Regularizer for L1
+{
+    fn reg_penalty<F, const P: usize>(&self, params: [F; P]) -> F
+    where
+        F: Float
+    {
+        f!(self.strength; F)*params.into_iter().fold(F::zero(), |s, param| s + param.abs())
+    }
+    fn reg_penalty_grad<F, const P: usize>(&self, params: [F; P]) -> [F; P]
+    where
+        F: Float
+    {
+        let strength = f!(self.strength; F);
+
+        params.map(|param| if param.is_zero() {F::zero()} else {strength.copysign(param)})
+    }
+    fn reg_prox<F, const P: usize>(&self, params: [F; P], step: F) -> [F; P]
+    where
+        F: Float
+    {
+        let threshold = step*f!(self.strength; F);
+
+        params.map(|param| soft_threshold(param, threshold))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::tests as t;
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        // The lasso zeroes the small coefficient
+        t::test_regularizer(L1::new(1.0), [2.5, 0.0, 0.5]);
+
+        assert_eq!(L1::new(0.5).reg_penalty([1.0, -2.0, 0.0]), 1.5);
+        assert_eq!(L1::new(0.5).reg_penalty_grad([1.0, -2.0, 0.0]), [0.5, -0.5, 0.0]);
+    }
+}
@@ -0,0 +1,76 @@
+use num::Float;
+
+use super::*;
+
+/// The ridge penalty `strength*Σparams²/2`, which shrinks parameters in proportion to their size.
+#[derive(Clone, Copy, Debug)]
+pub struct L2
+{
+    pub strength: f64
+}
+
+impl L2
+{
+    pub fn new(strength: f64) -> Self
+    {
+        Self {
+            strength
+        }
+    }
+}
+
+impl Regularizer for L2
+{
+    fn reg_penalty<F, const P: usize>(&self, params: [F; P]) -> F
+    where
+        F: Float
+    {
+        f!(self.strength*0.5; F)*params.into_iter().fold(F::zero(), |s, param| s + param*param)
+    }
+    fn reg_penalty_grad<F, const P: usize>(&self, params: [F; P]) -> [F; P]
+    where
+        F: Float
+    {
+        let strength = f!(self.strength; F);
+
+        params.map(|param| strength*param)
+    }
+    fn reg_prox<F, const P: usize>(&self, params: [F; P], step: F) -> [F; P]
+    where
+        F: Float
+    {
+        let shrink = (F::one() + step*f!(self.strength; F)).recip();
+
+        params.map(|param| param*shrink)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::{optim::{chain_loss_grad, Optimizer, Sgd}, tests as t, LossFunction, MeanSquareError};
+
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        let w_ridge = [2.0, -0.2/1.5, 1.0/1.5];
+        t::test_regularizer(L2::new(1.0), w_ridge);
+
+        // Being smooth, the penalty can also be followed by its gradient
+        let x = [[1.0, 1.0, 1.0], [-1.0, 1.0, -1.0], [1.0, -1.0, -1.0], [-1.0, -1.0, 1.0]];
+        let y_true = x.map(|x| x.mul_dot([3.0, -0.2, 1.0]));
+
+        let reg = L2::new(1.0);
+        let mut opt = Sgd::new(0.1, 0.0, false);
+        let mut w = [0.0; 3];
+        for _ in 0..500
+        {
+            let y_est = x.map(|x| x.mul_dot(w));
+            let grad = reg.reg_add_grad(w, chain_loss_grad(MeanSquareError.lf_loss_grad(y_true, y_est), x));
+            opt.opt_step(&mut w, grad);
+        }
+        assert!(w.comap(w_ridge, |w, w_ridge| (w - w_ridge).abs()).into_iter().all(|e| e < 1e-9), "{:?}", w);
+    }
+}
@@ -0,0 +1,11 @@
+use super::*;
+
+moddef::moddef!(
+    flat(pub) mod {
+        regularizer,
+
+        l1,
+        l2,
+        elastic_net
+    }
+);
@@ -0,0 +1,55 @@
+use num::Float;
+
+use super::*;
+
+/// A penalty on parameters, to be added to the loss of the estimates the parameters produce.
+/// 
+/// Smooth penalties can be followed by adding [`reg_penalty_grad`](Regularizer::reg_penalty_grad) to the chained loss gradient,
+/// while non-smooth ones are better handled by proximal gradient descent, stepping against the loss gradient alone and then applying [`reg_prox`](Regularizer::reg_prox).
+pub trait Regularizer
+{
+    fn reg_penalty<F, const P: usize>(&self, params: [F; P]) -> F
+    where
+        F: Float;
+
+    /// The gradient of the penalty, or a subgradient where it is not differentiable.
+    fn reg_penalty_grad<F, const P: usize>(&self, params: [F; P]) -> [F; P]
+    where
+        F: Float;
+
+    /// The proximal operator `argmin_u penalty(u) + |u - params|²/(2*step)`.
+    fn reg_prox<F, const P: usize>(&self, params: [F; P], step: F) -> [F; P]
+    where
+        F: Float;
+
+    /// Adds the gradient of the penalty to the gradient `grad` of the loss with respect to `params`.
+    fn reg_add_grad<F, const P: usize>(&self, params: [F; P], grad: [F; P]) -> [F; P]
+    where
+        F: Float
+    {
+        grad.comap(self.reg_penalty_grad(params), |grad, reg_grad| grad + reg_grad)
+    }
+}
+
+/// Shrinks `x` towards zero by `threshold`, stopping at zero.
+pub fn soft_threshold<F>(x: F, threshold: F) -> F
+where
+    F: Float
+{
+    (x.abs() - threshold).max(F::zero()).copysign(x)
+}
+
+#[cfg(test)]
+mod test
+{
+    use super::*;
+
+    #[test]
+    fn test()
+    {
+        assert_eq!(soft_threshold(3.0, 0.5), 2.5);
+        assert_eq!(soft_threshold(-3.0, 0.5), -2.5);
+        assert_eq!(soft_threshold(0.2, 0.5), 0.0);
+        assert_eq!(soft_threshold(-0.2, 0.5), 0.0);
+    }
+}